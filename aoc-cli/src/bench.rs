@@ -0,0 +1,296 @@
+//! Time a day's solver and compare against a saved baseline
+
+use advent_of_code::{Part, Solver, error::AoCError};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Fastest, median and slowest time of something over every run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+/// Timings of one part over every run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub part: u8,
+    #[serde(flatten)]
+    pub timing: Timing,
+}
+
+/// Timings of a day, as saved to and loaded from a JSON baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bench {
+    pub year: u16,
+    pub day: u8,
+    pub runs: usize,
+    /// Timings of parsing the input on its own, for days with a separate parsing step
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse: Option<Timing>,
+    pub parts: Vec<PartTiming>,
+}
+
+impl Bench {
+    pub fn load(path: &Path) -> io::Result<Bench> {
+        let json = fs::read_to_string(path)?;
+
+        serde_json::from_str(&json).map_err(io::Error::other)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+
+        fs::write(path, json)
+    }
+
+    fn part(&self, part: u8) -> Option<&Timing> {
+        self.parts
+            .iter()
+            .find(|timing| timing.part == part)
+            .map(|timing| &timing.timing)
+    }
+}
+
+impl Timing {
+    fn from_samples(mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+
+        Timing {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            max_ns: nanos(samples[samples.len() - 1]),
+        }
+    }
+}
+
+/// Parses the input and solves both parts `runs` times each.
+///
+/// Parsing is only timed on its own for days with a separate parsing step, and
+/// each part's timings still include the parsing it does itself. Parts that aren't
+/// implemented are skipped.
+pub fn bench(solver: &dyn Solver, input: &str, runs: usize) -> Result<Bench, AoCError> {
+    let runs = runs.max(1);
+    let mut parse = None;
+    let mut parts = vec![];
+
+    if solver.parse(input).transpose()?.is_some() {
+        let mut samples = Vec::with_capacity(runs);
+
+        for _ in 0..runs {
+            let start = Instant::now();
+            let result = solver.parse(input);
+            samples.push(start.elapsed());
+
+            if let Some(Err(err)) = result {
+                return Err(err);
+            }
+        }
+
+        parse = Some(Timing::from_samples(samples));
+    }
+
+    'parts: for (number, part) in [(1, Part::One), (2, Part::Two)] {
+        let mut samples = Vec::with_capacity(runs);
+
+        for _ in 0..runs {
+            let start = Instant::now();
+            let result = solver.solve_part(part, input);
+            let elapsed = start.elapsed();
+
+            match result {
+                Ok(_) => samples.push(elapsed),
+                Err(AoCError::NotImplemented { .. }) => continue 'parts,
+                Err(err) => return Err(err),
+            }
+        }
+
+        parts.push(PartTiming {
+            part: number,
+            timing: Timing::from_samples(samples),
+        });
+    }
+
+    Ok(Bench {
+        year: solver.year(),
+        day: solver.day(),
+        runs,
+        parse,
+        parts,
+    })
+}
+
+/// Change of a median relative to the baseline, as a percentage
+pub fn change(current: &Timing, baseline: &Timing) -> f64 {
+    if baseline.median_ns == 0 {
+        return 0.0;
+    }
+
+    (current.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns as f64 * 100.0
+}
+
+/// Prints the timings, compared against the baseline when there is one.
+///
+/// Returns whether any median got slower than the baseline by more than
+/// `threshold` percent.
+pub fn report(bench: &Bench, baseline: Option<&Bench>, threshold: f64) -> bool {
+    let mut regressed = false;
+
+    let parse = bench.parse.iter().map(|timing| {
+        let previous = baseline.and_then(|baseline| baseline.parse.as_ref());
+
+        ("Parse ".to_string(), timing, previous)
+    });
+    let parts = bench.parts.iter().map(|timing| {
+        let previous = baseline.and_then(|baseline| baseline.part(timing.part));
+
+        (format!("Part {}", timing.part), &timing.timing, previous)
+    });
+
+    for (name, timing, previous) in parse.chain(parts) {
+        print!(
+            "{name}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            Duration::from_nanos(timing.min_ns),
+            Duration::from_nanos(timing.median_ns),
+            Duration::from_nanos(timing.max_ns),
+        );
+
+        match previous {
+            Some(previous) => {
+                let change = change(timing, previous);
+
+                if change > threshold {
+                    regressed = true;
+                    println!("  {change:+.1}% REGRESSION");
+                } else {
+                    println!("  {change:+.1}%");
+                }
+            }
+            None => println!(),
+        }
+    }
+
+    regressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(median_ns: u64) -> Timing {
+        Timing {
+            min_ns: median_ns,
+            median_ns,
+            max_ns: median_ns,
+        }
+    }
+
+    fn part(part: u8, median_ns: u64) -> PartTiming {
+        PartTiming {
+            part,
+            timing: timing(median_ns),
+        }
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let samples = [5, 1, 3, 2, 4].map(Duration::from_nanos).to_vec();
+
+        assert_eq!(
+            Timing::from_samples(samples),
+            Timing {
+                min_ns: 1,
+                median_ns: 3,
+                max_ns: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn flags_regressions_over_the_threshold() {
+        let baseline = Bench {
+            year: 2017,
+            day: 1,
+            runs: 1,
+            parse: Some(timing(100)),
+            parts: vec![part(1, 100), part(2, 100)],
+        };
+
+        let faster = Bench {
+            parts: vec![part(1, 50), part(2, 105)],
+            ..baseline.clone()
+        };
+        let slower = Bench {
+            parts: vec![part(1, 100), part(2, 150)],
+            ..baseline.clone()
+        };
+        let slower_parse = Bench {
+            parse: Some(timing(200)),
+            ..baseline.clone()
+        };
+
+        assert_eq!(
+            change(&faster.parts[0].timing, &baseline.parts[0].timing),
+            -50.0
+        );
+        assert!(!report(&faster, Some(&baseline), 10.0));
+        assert!(report(&slower, Some(&baseline), 10.0));
+        assert!(report(&slower_parse, Some(&baseline), 10.0));
+    }
+
+    #[test]
+    fn skips_unfinished_parts() {
+        let solver = advent_of_code::solver::find(2023, 10).unwrap();
+        let bench = bench(solver, include_str!("../../aoc/src/aoc2023/input/day10"), 2).unwrap();
+
+        assert_eq!(bench.runs, 2);
+        assert_eq!(bench.parts.iter().map(|t| t.part).collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn skips_unfinished_parts_in_a_single_run() {
+        let solver = advent_of_code::solver::find(2025, 2).unwrap();
+        let bench = bench(solver, "", 1).unwrap();
+
+        assert_eq!(bench.runs, 1);
+        assert!(bench.parts.is_empty());
+        assert_eq!(bench.parse, None);
+    }
+
+    #[test]
+    fn times_parsing_on_its_own() {
+        let solver = advent_of_code::solver::find(2017, 1).unwrap();
+        let bench = bench(solver, "1122", 3).unwrap();
+
+        assert!(bench.parse.is_some());
+        assert_eq!(bench.parts.len(), 2);
+        assert!(super::bench(solver, "", 3).is_err());
+    }
+
+    #[test]
+    fn baseline_round_trips_through_json() {
+        let path = std::env::temp_dir().join(format!(
+            "aoc-cli-bench-baseline-{}.json",
+            std::process::id()
+        ));
+        let bench = Bench {
+            year: 2023,
+            day: 5,
+            runs: 3,
+            parse: Some(timing(7)),
+            parts: vec![part(1, 42)],
+        };
+
+        bench.save(&path).unwrap();
+        let loaded = Bench::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap(), bench);
+    }
+}
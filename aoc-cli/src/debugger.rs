@@ -0,0 +1,362 @@
+//! Interactive debugger for intcode programs
+//!
+//! Reads commands a line at a time, runs them against an [`intcode::debug::Debugger`]
+//! and prints what happened, along with anything the program output.
+
+use std::fs::File;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use intcode::asm;
+use intcode::debug::{Breakpoint, Debugger, Stop};
+use intcode::intcode::{Cursor, Value};
+
+const HELP: &str = "\
+step [n]              execute the next n instructions, 1 by default (s)
+continue              run until a breakpoint, watchpoint, input or halt (c)
+break <addr|op>       stop before an address, or any instruction like add or op99 (b)
+delete <addr|op>      remove a breakpoint (d)
+watch <addr>          stop when the value at an address changes (w)
+unwatch <addr>        stop watching an address
+registers             print the cursor, relative base and next instruction (r)
+memory <addr> [n]     print n values from an address, 8 by default (m)
+set <addr> <values>   overwrite memory from an address
+input <values>        queue input values (i)
+trace <file>          write the executed instructions to a file
+help                  print this message (h)
+quit                  stop debugging (q)";
+
+/// Values `memory` shows past the end of memory, which are all zero
+const SHOWN_PAST_END: usize = 8;
+
+/// How far past the end of memory `set` may write, so a mistyped address doesn't
+/// grow memory to an absurd size
+const MAX_SET_GROWTH: usize = 1 << 20;
+
+/// Command read from the prompt
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    Delete(Breakpoint),
+    Watch(Cursor),
+    Unwatch(Cursor),
+    Registers,
+    Memory(Cursor, usize),
+    Set(Cursor, Vec<Value>),
+    Input(Vec<Value>),
+    Trace(PathBuf),
+    Help,
+    Quit,
+}
+
+fn number<T: std::str::FromStr>(text: Option<&str>, what: &str) -> Result<T, String> {
+    let text = text.ok_or_else(|| format!("missing {what}"))?;
+
+    text.parse().map_err(|_| format!("invalid {what} {text:?}"))
+}
+
+fn breakpoint(text: Option<&str>) -> Result<Breakpoint, String> {
+    let text = text.ok_or("missing address or op")?;
+
+    if let Ok(address) = text.parse() {
+        return Ok(Breakpoint::Address(address));
+    }
+
+    text.strip_prefix("op")
+        .and_then(|code| code.parse().ok())
+        .or_else(|| asm::op_code(text))
+        .map(Breakpoint::OpCode)
+        .ok_or_else(|| format!("invalid address or op {text:?}"))
+}
+
+fn values<'a>(words: impl Iterator<Item = &'a str>) -> Result<Vec<Value>, String> {
+    words
+        .flat_map(|word| word.split(','))
+        .filter(|value| !value.is_empty())
+        .map(|value| number(Some(value), "value"))
+        .collect()
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Command, String> {
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default();
+
+        let command = match name {
+            "s" | "step" => Command::Step(match words.next() {
+                Some(count) => number(Some(count), "count")?,
+                None => 1,
+            }),
+            "c" | "continue" => Command::Continue,
+            "b" | "break" => Command::Break(breakpoint(words.next())?),
+            "d" | "delete" => Command::Delete(breakpoint(words.next())?),
+            "w" | "watch" => Command::Watch(number(words.next(), "address")?),
+            "unwatch" => Command::Unwatch(number(words.next(), "address")?),
+            "r" | "registers" => Command::Registers,
+            "m" | "memory" => Command::Memory(
+                number(words.next(), "address")?,
+                match words.next() {
+                    Some(count) => number(Some(count), "count")?,
+                    None => 8,
+                },
+            ),
+            "set" => Command::Set(number(words.next(), "address")?, values(words)?),
+            "i" | "input" => Command::Input(values(words)?),
+            "trace" => Command::Trace(words.next().ok_or("missing file")?.into()),
+            "h" | "help" => Command::Help,
+            "q" | "quit" => Command::Quit,
+            _ => return Err(format!("unknown command {name:?}, try help")),
+        };
+
+        Ok(command)
+    }
+}
+
+/// Prints the next instruction, with its address
+fn next_instruction(debugger: &Debugger) -> String {
+    let executor = debugger.executor();
+
+    match executor.instruction() {
+        Ok(instruction) => format!("{:04}  {}", executor.cursor(), instruction),
+        Err(err) => format!("{:04}  {}", executor.cursor(), err),
+    }
+}
+
+/// Runs a command, returning what to print
+fn execute(debugger: &mut Debugger, command: Command) -> Result<String, String> {
+    let stop = match command {
+        Command::Step(count) => {
+            let mut stop = Stop::Stepped;
+
+            for _ in 0..count {
+                stop = debugger.step().map_err(|err| err.to_string())?;
+
+                if stop != Stop::Stepped {
+                    break;
+                }
+            }
+
+            stop
+        }
+        Command::Continue => debugger.resume().map_err(|err| err.to_string())?,
+        Command::Break(breakpoint) => {
+            debugger.add_breakpoint(breakpoint);
+            return Ok(format!("breakpoint at {breakpoint}"));
+        }
+        Command::Delete(breakpoint) => {
+            return match debugger.remove_breakpoint(breakpoint) {
+                true => Ok(format!("removed breakpoint at {breakpoint}")),
+                false => Err(format!("no breakpoint at {breakpoint}")),
+            };
+        }
+        Command::Watch(address) => {
+            debugger.watch(address);
+            return Ok(format!("watching address {address}"));
+        }
+        Command::Unwatch(address) => {
+            return match debugger.unwatch(address) {
+                true => Ok(format!("stopped watching address {address}")),
+                false => Err(format!("address {address} isn't watched")),
+            };
+        }
+        Command::Registers => {
+            let executor = debugger.executor();
+
+            return Ok(format!(
+                "cursor {}, relative base {}, {} steps\n{}",
+                executor.cursor(),
+                executor.relative_base(),
+                debugger.steps(),
+                next_instruction(debugger)
+            ));
+        }
+        Command::Memory(address, count) => {
+            let memory = debugger.executor().memory();
+            let count = count.min(memory.len().saturating_sub(address) + SHOWN_PAST_END);
+
+            let values: Vec<String> = (address..address.saturating_add(count))
+                .map(|address| memory.get(address).copied().unwrap_or(0).to_string())
+                .collect();
+
+            return Ok(format!("{address:04}: {}", values.join(", ")));
+        }
+        Command::Set(address, values) => {
+            let size = debugger.executor().memory().len();
+
+            if address > size.saturating_add(MAX_SET_GROWTH) {
+                return Err(format!(
+                    "address {address} is too far past the end of memory, which has {size} values"
+                ));
+            }
+
+            debugger
+                .executor_mut()
+                .write_memory(address, &values)
+                .map_err(|err| err.to_string())?;
+
+            return Ok(format!("wrote {} values at {address}", values.len()));
+        }
+        Command::Input(values) => {
+            for value in &values {
+                debugger.executor_mut().push_input(*value);
+            }
+
+            return Ok(format!("queued {} values", values.len()));
+        }
+        Command::Trace(path) => {
+            let written = File::create(&path).and_then(|file| debugger.write_trace(file));
+            written.map_err(|err| format!("error writing {}: {}", path.display(), err))?;
+
+            return Ok(format!(
+                "wrote {} instructions to {}",
+                debugger.trace().count(),
+                path.display()
+            ));
+        }
+        Command::Help => return Ok(HELP.to_string()),
+        Command::Quit => return Ok(String::new()),
+    };
+
+    let mut report = String::new();
+
+    for value in debugger.take_output() {
+        report += &format!("output {value}\n");
+    }
+
+    if stop != Stop::Stepped {
+        report += &format!("{stop}\n");
+    }
+
+    report += &next_instruction(debugger);
+
+    Ok(report)
+}
+
+/// Reads commands from `input` until it ends or the quit command, writing the results
+/// to `output`
+pub fn repl(
+    debugger: &mut Debugger,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    writeln!(output, "{}", next_instruction(debugger))?;
+
+    for line in input.lines() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match Command::parse(&line) {
+            Ok(Command::Quit) => break,
+            Ok(command) => match execute(debugger, command) {
+                Ok(report) => writeln!(output, "{report}")?,
+                Err(err) => writeln!(output, "error: {err}")?,
+            },
+            Err(err) => writeln!(output, "error: {err}")?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use intcode::intcode::IntCodeExecutor;
+
+    fn session(program: &[Value], commands: &str) -> String {
+        let mut debugger = Debugger::new(IntCodeExecutor::new(program));
+        let mut output = vec![];
+
+        repl(&mut debugger, commands.as_bytes(), &mut output).unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(Command::parse("s"), Ok(Command::Step(1)));
+        assert_eq!(Command::parse("step 10"), Ok(Command::Step(10)));
+        assert_eq!(
+            Command::parse("b 12"),
+            Ok(Command::Break(Breakpoint::Address(12)))
+        );
+        assert_eq!(
+            Command::parse("break jt"),
+            Ok(Command::Break(Breakpoint::OpCode(5)))
+        );
+        assert_eq!(
+            Command::parse("break op99"),
+            Ok(Command::Break(Breakpoint::OpCode(99)))
+        );
+        assert_eq!(
+            Command::parse("set 3 1, 2,3"),
+            Ok(Command::Set(3, vec![1, 2, 3]))
+        );
+        assert_eq!(
+            Command::parse("break 99"),
+            Ok(Command::Break(Breakpoint::Address(99)))
+        );
+        assert!(Command::parse("break nowhere").is_err());
+        assert!(Command::parse("jump").is_err());
+    }
+
+    #[test]
+    fn runs_a_session() {
+        let output = session(
+            &[3, 9, 1, 9, 9, 9, 4, 9, 99, 0],
+            &format!(
+                "c\ni 21\nw 9\nc\nr\nset 9 5\nm 8 2\nm {} 5\nb out\ndelete 4\nc\nc\nq\nc\n",
+                usize::MAX - 1
+            ),
+        );
+
+        assert_eq!(
+            output,
+            "0000  in 9\n\
+             waiting on input\n\
+             0000  in 9\n\
+             queued 1 values\n\
+             watching address 9\n\
+             address 9 changed from 0 to 21\n\
+             0002  add 9, 9, 9\n\
+             cursor 2, relative base 0, 1 steps\n\
+             0002  add 9, 9, 9\n\
+             wrote 1 values at 9\n\
+             0008: 99, 5\n\
+             18446744073709551614: 0\n\
+             breakpoint at op code 4\n\
+             error: no breakpoint at address 4\n\
+             address 9 changed from 5 to 10\n\
+             0006  out 9\n\
+             output 10\n\
+             halted\n\
+             0008  hlt\n"
+        );
+    }
+
+    #[test]
+    fn keeps_memory_commands_in_bounds() {
+        let output = session(
+            &[99, 7],
+            &format!(
+                "m 0 {max}\nset {max} 1,2\nset 100000000000000 1\nset 3 4\nm 1\nq\n",
+                max = usize::MAX
+            ),
+        );
+
+        assert_eq!(
+            output,
+            "0000  hlt\n\
+             0000: 99, 7, 0, 0, 0, 0, 0, 0, 0, 0\n\
+             error: address 18446744073709551615 is too far past the end of memory, which has 2 values\n\
+             error: address 100000000000000 is too far past the end of memory, which has 2 values\n\
+             wrote 1 values at 3\n\
+             0001: 7, 0, 4, 0, 0, 0, 0, 0\n"
+        );
+    }
+}
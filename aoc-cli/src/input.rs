@@ -0,0 +1,299 @@
+//! Find puzzle inputs on disk, or download them from adventofcode.com
+//!
+//! Inputs are looked up in the repository first, then in a per-user cache. When
+//! a session token is configured, missing inputs are downloaded and saved to the
+//! cache so each one is only fetched once.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Inputs checked into the repository, next to each year's solutions
+pub const REPO_INPUTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc/src");
+
+/// Site the inputs are downloaded from, unless `AOC_BASE_URL` is set
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Path to the input of a day inside an inputs directory laid out like the repository
+pub fn input_path(inputs: &Path, year: u16, day: u8) -> PathBuf {
+    inputs.join(format!("aoc{year}/input/day{day:02}"))
+}
+
+/// Source of puzzle inputs
+pub trait InputProvider {
+    /// Input of a day, `None` when the provider doesn't have it
+    fn input(&self, year: u16, day: u8) -> io::Result<Option<String>>;
+}
+
+/// Inputs stored in a directory laid out like the repository, as `aocYYYY/input/dayNN`
+pub struct FileStore {
+    root: PathBuf,
+}
+
+impl FileStore {
+    pub fn new(root: impl Into<PathBuf>) -> FileStore {
+        FileStore { root: root.into() }
+    }
+
+    /// Inputs checked into the repository
+    pub fn repository() -> FileStore {
+        FileStore::new(REPO_INPUTS)
+    }
+
+    /// Cache of downloaded inputs, in `AOC_CACHE_DIR` or the user's cache directory
+    pub fn user_cache() -> Option<FileStore> {
+        match env::var_os("AOC_CACHE_DIR") {
+            Some(root) => Some(FileStore::new(root)),
+            None => dirs::cache_dir().map(|root| FileStore::new(root.join("aoc-cli"))),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8, input: &str) -> io::Result<()> {
+        let path = input_path(&self.root, year, day);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, input)
+    }
+}
+
+impl InputProvider for FileStore {
+    fn input(&self, year: u16, day: u8) -> io::Result<Option<String>> {
+        match fs::read_to_string(input_path(&self.root, year, day)) {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Downloads inputs from adventofcode.com, or a server with the same API
+pub struct Http {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Http {
+    pub fn new(base_url: &str, session: &str) -> Http {
+        let agent = ureq::Agent::config_builder()
+            .user_agent("github.com/m-rutter/advent-of-code aoc-cli")
+            .build()
+            .into();
+
+        Http {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Downloads with the session token from `AOC_SESSION` or `<config dir>/aoc-cli/session`,
+    /// from `AOC_BASE_URL` when set.
+    ///
+    /// Returns `None` when there is no session token.
+    pub fn from_env() -> Option<Http> {
+        let session = env::var("AOC_SESSION").ok().or_else(|| {
+            let path = dirs::config_dir()?.join("aoc-cli/session");
+            fs::read_to_string(path).ok()
+        })?;
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Some(Http::new(&base_url, &session))
+    }
+
+    /// Fetches `path` from the server, returning the response body
+    pub fn get(&self, path: &str) -> io::Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call();
+
+        Http::read_body(&url, response)
+    }
+
+    /// Posts a form to `path` on the server, returning the response body
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> io::Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form(form.iter().copied());
+
+        Http::read_body(&url, response)
+    }
+
+    fn read_body(
+        url: &str,
+        response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    ) -> io::Result<String> {
+        response
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| io::Error::other(format!("request to {url} failed: {err}")))
+    }
+}
+
+impl InputProvider for Http {
+    fn input(&self, year: u16, day: u8) -> io::Result<Option<String>> {
+        self.get(&format!("/{year}/day/{day}/input")).map(Some)
+    }
+}
+
+/// Provider that saves everything `upstream` provides to a store
+pub struct Cached<P> {
+    store: FileStore,
+    upstream: P,
+}
+
+impl<P: InputProvider> Cached<P> {
+    pub fn new(store: FileStore, upstream: P) -> Cached<P> {
+        Cached { store, upstream }
+    }
+}
+
+impl<P: InputProvider> InputProvider for Cached<P> {
+    fn input(&self, year: u16, day: u8) -> io::Result<Option<String>> {
+        if let Some(input) = self.store.input(year, day)? {
+            return Ok(Some(input));
+        }
+
+        let input = self.upstream.input(year, day)?;
+
+        if let Some(input) = &input {
+            self.store.save(year, day, input)?;
+        }
+
+        Ok(input)
+    }
+}
+
+/// Tries each provider in turn
+impl InputProvider for Vec<Box<dyn InputProvider>> {
+    fn input(&self, year: u16, day: u8) -> io::Result<Option<String>> {
+        for provider in self.iter() {
+            if let Some(input) = provider.input(year, day)? {
+                return Ok(Some(input));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Looks in the repository, then the user's cache, downloading into the cache when a
+/// session token is configured
+pub fn default_provider() -> Vec<Box<dyn InputProvider>> {
+    let mut providers: Vec<Box<dyn InputProvider>> = vec![Box::new(FileStore::repository())];
+
+    match (FileStore::user_cache(), Http::from_env()) {
+        (Some(cache), Some(http)) => providers.push(Box::new(Cached::new(cache, http))),
+        (Some(cache), None) => providers.push(Box::new(cache)),
+        (None, Some(http)) => providers.push(Box::new(http)),
+        (None, None) => {}
+    }
+
+    providers
+}
+
+/// Input of a day from `provider`, an error when it doesn't have one
+pub fn fetch(provider: &dyn InputProvider, year: u16, day: u8) -> io::Result<String> {
+    provider.input(year, day)?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "no input for day {day} of {year}, pass one with --path or set AOC_SESSION to \
+                 download it"
+            ),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::serve_once;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-cli-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        root
+    }
+
+    #[test]
+    fn finds_repository_inputs() {
+        let path = input_path(Path::new(REPO_INPUTS), 2017, 1);
+
+        assert!(path.ends_with("aoc2017/input/day01"));
+        assert!(path.is_file());
+    }
+
+    #[test]
+    fn reads_repository_inputs() {
+        let store = FileStore::repository();
+
+        assert!(store.input(2017, 1).unwrap().is_some());
+        assert!(store.input(2017, 25).unwrap().is_none());
+    }
+
+    #[test]
+    fn downloads_with_the_session_token() {
+        let (base_url, server) = serve_once("200 OK", "1\n2\n3\n");
+        let http = Http::new(&format!("{base_url}/"), "secret\n");
+
+        assert_eq!(http.input(2023, 8).unwrap().as_deref(), Some("1\n2\n3\n"));
+
+        let request = server.join().unwrap();
+
+        assert!(request.starts_with("GET /2023/day/8/input HTTP/1.1\n"));
+        assert!(request.to_lowercase().contains("cookie: session=secret\n"));
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+        let http = Http::new(&base_url, "expired");
+
+        assert!(http.input(2023, 8).is_err());
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn caches_downloads() {
+        let (base_url, server) = serve_once("200 OK", "input");
+        let root = temp_dir("cache");
+        let cached = Cached::new(FileStore::new(&root), Http::new(&base_url, "secret"));
+
+        assert_eq!(cached.input(2019, 2).unwrap().as_deref(), Some("input"));
+        server.join().unwrap();
+
+        // the server only answers once, so this has to come from the cache
+        assert_eq!(cached.input(2019, 2).unwrap().as_deref(), Some("input"));
+        assert!(input_path(&root, 2019, 2).is_file());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn tries_providers_in_order() {
+        let empty = FileStore::new(temp_dir("empty"));
+        let providers: Vec<Box<dyn InputProvider>> =
+            vec![Box::new(empty), Box::new(FileStore::repository())];
+
+        assert!(fetch(&providers, 2017, 1).is_ok());
+        assert_eq!(
+            fetch(&providers, 2017, 25).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+}
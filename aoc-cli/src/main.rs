@@ -1,27 +1,210 @@
-use advent_of_code::{solve_day, Config};
-use clap::Parser;
-use std::fs::File;
+mod bench;
+mod debugger;
+mod input;
+mod play;
+mod run;
+mod scaffold;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod verify;
+
+use advent_of_code::{Config, Part, solve_day, solver};
+use clap::{Parser, Subcommand};
+use intcode::debug::Debugger;
+use intcode::intcode::{IntCodeExecutor, Value};
+use std::fs::{self, File};
 use std::io::{self, Read};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 
 #[derive(Debug, Parser)]
-#[command(name = "Advent of Code Cli", about = "Solves Advent of Code problems")]
+#[command(
+    name = "Advent of Code Cli",
+    about = "Solves Advent of Code problems",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Opt {
     /// Set the day to solve
-    #[arg(short = 'd', long = "day")]
-    day: u8,
+    #[arg(short = 'd', long = "day", required = true)]
+    day: Option<u8>,
     /// Set the year
-    #[arg(short = 'y', long = "year")]
-    year: u16,
-    /// Set the input file as problem input
+    #[arg(short = 'y', long = "year", required = true)]
+    year: Option<u16>,
+    /// Set the input file as problem input, or - for stdin, otherwise the stored input is used
     #[arg(short = 'p', long = "path")]
     path: Option<PathBuf>,
+    /// Only solve this part of the day
+    #[arg(long = "part", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Fail on input lines that would otherwise be skipped
+    #[arg(long = "strict")]
+    strict: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List the available solutions
+    List {
+        /// Only list solutions for this year
+        #[arg(short = 'y', long = "year")]
+        year: Option<u16>,
+    },
+    /// Solve every available day of a year, or of all years, and summarise the results
+    Run {
+        /// Solve the days of this year
+        #[arg(short = 'y', long = "year", required_unless_present = "all")]
+        year: Option<u16>,
+        /// Solve the days of every year
+        #[arg(short = 'a', long = "all", conflicts_with = "year")]
+        all: bool,
+        /// Directory holding the inputs as `aocYYYY/input/dayNN`, defaults to the repository's
+        #[arg(short = 'i', long = "inputs")]
+        inputs: Option<PathBuf>,
+        /// Fail on input lines that would otherwise be skipped
+        #[arg(long = "strict")]
+        strict: bool,
+    },
+    /// Check the solutions against the known answers for the repository's inputs
+    Verify {
+        /// Only check the days of this year
+        #[arg(short = 'y', long = "year")]
+        year: Option<u16>,
+        /// Directory holding the inputs as `aocYYYY/input/dayNN`, defaults to the repository's
+        #[arg(short = 'i', long = "inputs")]
+        inputs: Option<PathBuf>,
+        /// Fail on input lines that would otherwise be skipped
+        #[arg(long = "strict")]
+        strict: bool,
+    },
+    /// Solve a part of a day and submit the answer to adventofcode.com
+    Submit {
+        /// Set the day to solve
+        #[arg(short = 'd', long = "day")]
+        day: u8,
+        /// Set the year
+        #[arg(short = 'y', long = "year")]
+        year: u16,
+        /// Part to solve and submit
+        #[arg(long = "part", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Set the input file, defaults to the stored input for the day
+        #[arg(short = 'p', long = "path")]
+        path: Option<PathBuf>,
+        /// Fail on input lines that would otherwise be skipped
+        #[arg(long = "strict")]
+        strict: bool,
+    },
+    /// Generate the files for a new day, setting up its year if needed
+    New {
+        /// Set the day
+        #[arg(short = 'd', long = "day", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Set the year
+        #[arg(short = 'y', long = "year")]
+        year: u16,
+        /// Title of the puzzle
+        #[arg(short = 't', long = "title")]
+        title: Option<String>,
+    },
+    /// Time both parts of a day over several runs
+    Bench {
+        /// Set the day to benchmark
+        #[arg(short = 'd', long = "day")]
+        day: u8,
+        /// Set the year
+        #[arg(short = 'y', long = "year")]
+        year: u16,
+        /// Set the input file, defaults to the stored input for the day
+        #[arg(short = 'p', long = "path")]
+        path: Option<PathBuf>,
+        /// Number of times to solve each part
+        #[arg(short = 'n', long = "runs", default_value_t = 10)]
+        runs: usize,
+        /// Save the timings as a JSON baseline
+        #[arg(short = 's', long = "save")]
+        save: Option<PathBuf>,
+        /// Compare the timings against a JSON baseline
+        #[arg(short = 'b', long = "baseline")]
+        baseline: Option<PathBuf>,
+        /// Percentage a median may slow down by before it is flagged as a regression
+        #[arg(short = 't', long = "threshold", default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Tools for working with intcode programs
+    Intcode {
+        #[command(subcommand)]
+        command: IntcodeCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum IntcodeCommand {
+    /// Print a program as annotated assembly
+    Disasm {
+        /// File holding the comma separated program
+        path: PathBuf,
+    },
+    /// Step through a program interactively, with breakpoints and watched memory
+    Debug {
+        /// File holding the comma separated program
+        path: PathBuf,
+    },
+    /// Run a text based program, sending it the lines typed and printing its output
+    Play {
+        /// File holding the comma separated program
+        path: PathBuf,
+    },
 }
 
 fn main() {
     let opt = Opt::parse();
-    let config = create_config(&opt).unwrap_or_else(|err| {
+
+    match opt.command {
+        Some(Command::List { year }) => list(year),
+        Some(Command::Run {
+            year,
+            inputs,
+            strict,
+            ..
+        }) => run_all(year, inputs, strict),
+        Some(Command::Verify {
+            year,
+            inputs,
+            strict,
+        }) => verify(year, inputs, strict),
+        Some(Command::Submit {
+            day,
+            year,
+            part,
+            path,
+            strict,
+        }) => submit(year, day, part, path, strict),
+        Some(Command::New { day, year, title }) => new_day(year, day, title),
+        Some(Command::Bench {
+            day,
+            year,
+            path,
+            runs,
+            save,
+            baseline,
+            threshold,
+        }) => bench(year, day, path, runs, save, baseline, threshold),
+        Some(Command::Intcode { command }) => match command {
+            IntcodeCommand::Disasm { path } => disasm(&path),
+            IntcodeCommand::Debug { path } => debug(&path),
+            IntcodeCommand::Play { path } => play(&path),
+        },
+        None => solve(&opt),
+    }
+}
+
+fn solve(opt: &Opt) {
+    let config = create_config(opt).unwrap_or_else(|err| {
         eprintln!("Error reading input: {}", err);
         process::exit(1);
     });
@@ -31,35 +214,300 @@ fn main() {
         process::exit(1);
     });
 
+    if let Some(part_one) = solution.part_one {
+        println!(
+            "Solution to part 1 of day {} is: \n{}",
+            config.day, part_one
+        );
+    }
+
+    if let Some(part_two) = solution.part_two {
+        println!(
+            "Solution to part 2 of day {} is: \n{}",
+            config.day, part_two
+        );
+    }
+}
+
+fn list(year: Option<u16>) {
+    let solvers = solver::solvers().filter(|solver| year.is_none_or(|year| solver.year() == year));
+
+    for solver in solvers {
+        println!(
+            "{} day {:>2}: {}",
+            solver.year(),
+            solver.day(),
+            solver.title()
+        );
+    }
+}
+
+fn run_all(year: Option<u16>, inputs: Option<PathBuf>, strict: bool) {
+    let inputs = inputs.unwrap_or_else(|| PathBuf::from(input::REPO_INPUTS));
+
+    // clap requires --all when no year is given
+    let solved = match year {
+        Some(year) => run::run(solver::solvers_for_year(year), &inputs, strict),
+        None => run::run(solver::solvers(), &inputs, strict),
+    };
+
+    if !solved {
+        process::exit(1);
+    }
+}
+
+fn verify(year: Option<u16>, inputs: Option<PathBuf>, strict: bool) {
+    let inputs = inputs.unwrap_or_else(|| PathBuf::from(input::REPO_INPUTS));
+    let years: Vec<u16> = match year {
+        Some(year) => vec![year],
+        None => solver::years().collect(),
+    };
+
+    if !verify::verify(&years, &inputs, strict) {
+        process::exit(1);
+    }
+}
+
+fn bench(
+    year: u16,
+    day: u8,
+    path: Option<PathBuf>,
+    runs: usize,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) {
+    let solver = solver::find(year, day).unwrap_or_else(|| {
+        eprintln!("No solution for day {} of {}", day, year);
+        process::exit(1);
+    });
+
+    let input = read_stored_input(path, year, day).unwrap_or_else(|err| {
+        eprintln!("Error reading input: {}", err);
+        process::exit(1);
+    });
+
+    let baseline = baseline.map(|path| {
+        bench::Bench::load(&path).unwrap_or_else(|err| {
+            eprintln!("Error reading baseline {}: {}", path.display(), err);
+            process::exit(1);
+        })
+    });
+
+    if let Some(baseline) = &baseline {
+        if (baseline.year, baseline.day) != (year, day) {
+            eprintln!(
+                "Baseline is for day {} of {}, not day {} of {}",
+                baseline.day, baseline.year, day, year
+            );
+            process::exit(1);
+        }
+    }
+
+    let timings = bench::bench(solver, &input, runs).unwrap_or_else(|err| {
+        eprintln!("Error when attempting to solve day: {}", err);
+        process::exit(1);
+    });
+
     println!(
-        "Solution to part 1 of day {} is: \n{}",
-        config.day, solution.part_one
+        "{} day {}: {} ({} runs)",
+        year,
+        day,
+        solver.title(),
+        timings.runs
     );
+
+    let regressed = bench::report(&timings, baseline.as_ref(), threshold);
+
+    if let Some(path) = save {
+        timings.save(&path).unwrap_or_else(|err| {
+            eprintln!("Error saving baseline {}: {}", path.display(), err);
+            process::exit(1);
+        });
+    }
+
+    if regressed {
+        process::exit(1);
+    }
+}
+
+fn submit(year: u16, day: u8, part: u8, path: Option<PathBuf>, strict: bool) {
+    // clap restricts the part to 1 or 2
+    let part = if part == 1 { Part::One } else { Part::Two };
+
+    let input = read_stored_input(path, year, day).unwrap_or_else(|err| {
+        eprintln!("Error reading input: {}", err);
+        process::exit(1);
+    });
+
+    let mut config = Config::new(year, day, input).with_part(part);
+    config.strict = strict;
+
+    let solution = solve_day(&config).unwrap_or_else(|err| {
+        eprintln!("Error when attempting to solve day: {}", err);
+        process::exit(1);
+    });
+
+    let answer = solution
+        .part_one
+        .or(solution.part_two)
+        .expect("the requested part is solved");
+
+    let history_path = submit::History::default_path().unwrap_or_else(|| {
+        eprintln!("Unable to find a data directory for the submission history, set AOC_DATA_DIR");
+        process::exit(1);
+    });
+
+    let mut history = submit::History::load(&history_path).unwrap_or_else(|err| {
+        eprintln!("Error reading {}: {}", history_path.display(), err);
+        process::exit(1);
+    });
+
+    if let Some(reason) = history.refusal(year, day, part, &answer, submit::now()) {
+        eprintln!("Not submitting {}: {}", answer, reason);
+        process::exit(1);
+    }
+
+    let http = input::Http::from_env().unwrap_or_else(|| {
+        eprintln!("Set AOC_SESSION to the session token to submit answers");
+        process::exit(1);
+    });
+
     println!(
-        "Solution to part 2 of day {} is: \n{}",
-        config.day, solution.part_two
+        "Submitting {} for part {} of day {} {}",
+        answer, part, day, year
     );
+
+    let response = submit::submit(&http, year, day, part, &answer).unwrap_or_else(|err| {
+        eprintln!("Error submitting answer: {}", err);
+        process::exit(1);
+    });
+
+    history.record(year, day, part, &answer, &response, submit::now());
+    history.save(&history_path).unwrap_or_else(|err| {
+        eprintln!("Error saving {}: {}", history_path.display(), err);
+        process::exit(1);
+    });
+
+    println!("{}", response);
+
+    if response != submit::Response::Correct {
+        process::exit(1);
+    }
+}
+
+fn new_day(year: u16, day: u8, title: Option<String>) {
+    let title = title.unwrap_or_else(|| format!("Day {}", day));
+
+    let changed = scaffold::Scaffold::repository()
+        .day(year, day, &title)
+        .unwrap_or_else(|err| {
+            eprintln!("Error creating day {} of {}: {}", day, year, err);
+            process::exit(1);
+        });
+
+    for path in changed {
+        println!("{}", path.display());
+    }
+}
+
+fn disasm(path: &Path) {
+    let program = read_program(path).unwrap_or_else(|err| {
+        eprintln!("Error reading {}: {}", path.display(), err);
+        process::exit(1);
+    });
+
+    print!("{}", intcode::disasm::listing(&program));
+}
+
+fn debug(path: &Path) {
+    let program = read_program(path).unwrap_or_else(|err| {
+        eprintln!("Error reading {}: {}", path.display(), err);
+        process::exit(1);
+    });
+
+    println!("Debugging {}, type help for the commands", path.display());
+
+    let mut debugger = Debugger::new(IntCodeExecutor::new(&program));
+
+    debugger::repl(&mut debugger, io::stdin().lock(), io::stdout()).unwrap_or_else(|err| {
+        eprintln!("Error running the debugger: {}", err);
+        process::exit(1);
+    });
+}
+
+fn play(path: &Path) {
+    let program = read_program(path).unwrap_or_else(|err| {
+        eprintln!("Error reading {}: {}", path.display(), err);
+        process::exit(1);
+    });
+
+    let mut executor = IntCodeExecutor::new(&program);
+
+    play::play(&mut executor, io::stdin().lock(), io::stdout()).unwrap_or_else(|err| {
+        eprintln!("Error running {}: {}", path.display(), err);
+        process::exit(1);
+    });
 }
 
 fn create_config(opt: &Opt) -> io::Result<Config> {
-    let input = read_input_data(&opt.path)?;
+    // clap enforces both when no subcommand is given
+    let year = opt.year.expect("year is required");
+    let day = opt.day.expect("day is required");
+
+    let mut config = Config::new(year, day, read_input_data(&opt.path, year, day)?);
+
+    if opt.strict {
+        config = config.with_strict();
+    }
+
+    // clap restricts the part to 1 or 2
+    Ok(match opt.part {
+        Some(1) => config.with_part(Part::One),
+        Some(_) => config.with_part(Part::Two),
+        None => config,
+    })
+}
 
-    Ok(Config::new(opt.year, opt.day, input))
+/// Reads the input from `path`, otherwise from the stored inputs
+fn read_stored_input(path: Option<PathBuf>, year: u16, day: u8) -> io::Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path),
+        None => input::fetch(&input::default_provider(), year, day),
+    }
 }
 
-fn read_input_data(input_type: &Option<PathBuf>) -> io::Result<String> {
+/// Reads the input from `path`, or stdin when the path is `-`, otherwise from the
+/// stored inputs
+fn read_input_data(path: &Option<PathBuf>, year: u16, day: u8) -> io::Result<String> {
     let mut buff = String::new();
 
-    match input_type {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut stdin = io::stdin();
+            stdin.read_to_string(&mut buff)?;
+        }
         Some(path) => {
             let mut file = File::open(path)?;
             file.read_to_string(&mut buff)?;
         }
-        None => {
-            let mut stdin = io::stdin();
-            stdin.read_to_string(&mut buff)?;
-        }
+        None => buff = input::fetch(&input::default_provider(), year, day)?,
     }
 
     Ok(buff)
 }
+
+/// Reads a comma separated intcode program
+fn read_program(path: &Path) -> io::Result<Vec<Value>> {
+    fs::read_to_string(path)?
+        .split(',')
+        .map(|value| {
+            value.trim().parse().map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{:?} is not an intcode value: {}", value.trim(), err),
+                )
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,84 @@
+//! Play text based intcode programs at the terminal
+//!
+//! The program's ASCII output is printed as it is, with any other values printed on
+//! their own line, and each line typed is sent to the program as input.
+
+use std::io::{self, BufRead, Write};
+
+use intcode::intcode::{IntCodeExecutor, State};
+
+/// Runs a program until it halts or `input` ends, sending it the lines read from
+/// `input` and writing what it outputs to `output`
+pub fn play(
+    executor: &mut IntCodeExecutor,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<State> {
+    loop {
+        let state = executor.run().map_err(io::Error::other)?;
+        let text = executor.take_text();
+
+        write!(output, "{}", text.text)?;
+
+        for value in text.values {
+            writeln!(output, "{value}")?;
+        }
+
+        output.flush()?;
+
+        if state == State::Halted {
+            return Ok(state);
+        }
+
+        let mut line = String::new();
+
+        if input.read_line(&mut line)? == 0 {
+            return Ok(state);
+        }
+
+        executor.push_line(line.trim_end_matches(['\r', '\n']));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use intcode::asm::assemble;
+
+    #[test]
+    fn sends_lines_and_prints_text() {
+        // prompts with "?", echoes a line back, then outputs its answer
+        let program = assemble(
+            "
+                    out #63
+                    out #10
+            loop:   in c
+                    out c
+                    eq c, #10, end
+                    jf end, #loop
+                    out #1000
+                    hlt
+            c:      data 0
+            end:    data 0
+            ",
+        )
+        .unwrap();
+
+        let mut executor = IntCodeExecutor::new(&program);
+        let mut output = vec![];
+
+        let state = play(&mut executor, "go\r\nignored\n".as_bytes(), &mut output).unwrap();
+
+        assert_eq!(state, State::Halted);
+        assert_eq!(String::from_utf8(output).unwrap(), "?\ngo\n1000\n");
+    }
+
+    #[test]
+    fn stops_when_input_ends() {
+        let mut executor = IntCodeExecutor::new(&[3, 0, 99]);
+
+        let state = play(&mut executor, "".as_bytes(), io::sink()).unwrap();
+
+        assert_eq!(state, State::NeedsInput);
+    }
+}
@@ -0,0 +1,291 @@
+//! Solve many days in one go and summarise the results
+
+use crate::input::input_path;
+use advent_of_code::{Answer, Config, Part, Solver, error::AoCError, solve_day};
+use std::cell::Cell;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+/// Outcome of solving one part of a day
+pub enum Outcome {
+    Solved(Answer),
+    NotImplemented,
+    Failed(String),
+}
+
+impl Outcome {
+    fn cell(&self) -> String {
+        match self {
+            Outcome::Solved(Answer::Multiline(_)) => "(multi-line)".to_string(),
+            Outcome::Solved(answer) => answer.to_string(),
+            Outcome::NotImplemented => "-".to_string(),
+            Outcome::Failed(_) => "FAILED".to_string(),
+        }
+    }
+}
+
+/// Results of solving both parts of a day
+struct Report {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    parts: [Outcome; 2],
+    elapsed: Duration,
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` without printing panics on this thread, for when solver panics are
+/// reported some other way.
+///
+/// The panic hook is only replaced once, with one that skips printing while a
+/// thread is being quiet, so other threads keep printing their panics.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    static INSTALL: Once = Once::new();
+
+    /// Puts back the previous setting, even if `f` panics
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            QUIET.set(self.0);
+        }
+    }
+
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    let _restore = Restore(QUIET.replace(true));
+
+    f()
+}
+
+/// Solves every given day with the inputs found in `inputs`, printing a summary table.
+///
+/// Returns whether every day was solved without failures. Days that are not
+/// implemented yet don't count as failures. With `strict`, input lines a day would
+/// skip over fail it instead.
+pub fn run(
+    solvers: impl Iterator<Item = &'static dyn Solver>,
+    inputs: &Path,
+    strict: bool,
+) -> bool {
+    // failures are reported in the table, the panic hook would only add noise
+    let reports: Vec<Report> = quietly(|| {
+        solvers
+            .map(|solver| solve(solver, inputs, strict))
+            .collect()
+    });
+
+    print_table(&reports);
+
+    let failures: Vec<_> = reports
+        .iter()
+        .flat_map(|report| {
+            [Part::One, Part::Two]
+                .into_iter()
+                .zip(&report.parts)
+                .filter_map(move |(part, outcome)| match outcome {
+                    Outcome::Failed(error) => Some((report, part, error)),
+                    _ => None,
+                })
+        })
+        .collect();
+
+    if !failures.is_empty() {
+        println!();
+        println!("Failures:");
+
+        for (report, part, error) in failures.iter() {
+            println!(
+                "  {} day {:>2} part {}: {}",
+                report.year, report.day, part, error
+            );
+        }
+    }
+
+    let total: Duration = reports.iter().map(|report| report.elapsed).sum();
+    let solved = reports
+        .iter()
+        .flat_map(|report| report.parts.iter())
+        .filter(|outcome| matches!(outcome, Outcome::Solved(_)))
+        .count();
+
+    println!();
+    println!(
+        "{} days, {} parts solved, {} failed in {:.2?}",
+        reports.len(),
+        solved,
+        failures.len(),
+        total
+    );
+
+    failures.is_empty()
+}
+
+/// Solves the part of the day set in `config`
+pub fn solve_part(config: &Config) -> Outcome {
+    match solve_day(config) {
+        Ok(solution) => match solution.part_one.or(solution.part_two) {
+            Some(answer) => Outcome::Solved(answer),
+            None => Outcome::NotImplemented,
+        },
+        Err(AoCError::NotImplemented { .. }) => Outcome::NotImplemented,
+        Err(err) => Outcome::Failed(err.to_string()),
+    }
+}
+
+fn solve(solver: &'static dyn Solver, inputs: &Path, strict: bool) -> Report {
+    let (year, day) = (solver.year(), solver.day());
+
+    let mut report = Report {
+        year,
+        day,
+        title: solver.title(),
+        parts: [Outcome::NotImplemented, Outcome::NotImplemented],
+        elapsed: Duration::ZERO,
+    };
+
+    let path = input_path(inputs, year, day);
+
+    let input = match fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(err) => {
+            let error = format!("unable to read {}: {}", path.display(), err);
+            report.parts = [Outcome::Failed(error.clone()), Outcome::Failed(error)];
+            return report;
+        }
+    };
+
+    let mut config = Config::new(year, day, input);
+    config.strict = strict;
+
+    for (index, part) in [Part::One, Part::Two].into_iter().enumerate() {
+        config.part = Some(part);
+
+        let start = Instant::now();
+        report.parts[index] = solve_part(&config);
+        report.elapsed += start.elapsed();
+    }
+
+    report
+}
+
+fn print_table(reports: &[Report]) {
+    let header = ["Year", "Day", "Title", "Part 1", "Part 2", "Time"];
+
+    let rows: Vec<[String; 6]> = reports
+        .iter()
+        .map(|report| {
+            [
+                report.year.to_string(),
+                report.day.to_string(),
+                report.title.to_string(),
+                report.parts[0].cell(),
+                report.parts[1].cell(),
+                format!("{:.2?}", report.elapsed),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(|column| column.len());
+
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: [&str; 6]| {
+        let [year, day, title, part_one, part_two, time] = cells;
+        let [w_year, w_day, w_title, w_one, w_two, w_time] = widths;
+
+        println!(
+            "{year:<w_year$}  {day:>w_day$}  {title:<w_title$}  {part_one:<w_one$}  \
+             {part_two:<w_two$}  {time:>w_time$}"
+        );
+    };
+
+    line(header);
+
+    for row in rows.iter() {
+        line(row.each_ref().map(|cell| cell.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::REPO_INPUTS;
+
+    #[test]
+    fn missing_inputs_fail_the_day() {
+        let solver = advent_of_code::solver::find(2017, 1).unwrap();
+        let report = solve(solver, Path::new("/nonexistent"), false);
+
+        assert!(matches!(
+            report.parts,
+            [Outcome::Failed(_), Outcome::Failed(_)]
+        ));
+    }
+
+    #[test]
+    fn unfinished_parts_are_not_failures() {
+        let solver = advent_of_code::solver::find(2025, 2).unwrap();
+        let report = solve(solver, Path::new(REPO_INPUTS), false);
+
+        assert!(matches!(
+            report.parts,
+            [Outcome::NotImplemented, Outcome::NotImplemented]
+        ));
+    }
+
+    #[test]
+    fn strict_runs_fail_on_skipped_input() {
+        let inputs = std::env::temp_dir().join(format!("aoc-cli-run-{}", std::process::id()));
+        let path = input_path(&inputs, 2017, 1);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "1122x").unwrap();
+
+        let solver = advent_of_code::solver::find(2017, 1).unwrap();
+        let lenient = solve(solver, &inputs, false);
+        let strict = solve(solver, &inputs, true);
+        fs::remove_dir_all(&inputs).unwrap();
+
+        assert!(matches!(lenient.parts[0], Outcome::Solved(_)));
+        assert!(matches!(
+            strict.parts,
+            [Outcome::Failed(_), Outcome::Failed(_)]
+        ));
+    }
+
+    #[test]
+    fn quiet_only_while_running() {
+        let nested = quietly(|| (QUIET.get(), quietly(|| QUIET.get()), QUIET.get()));
+
+        assert_eq!(nested, (true, true, true));
+        assert!(!QUIET.get());
+        assert!(
+            std::thread::spawn(|| quietly(|| QUIET.get()))
+                .join()
+                .unwrap()
+        );
+
+        let caught = quietly(|| panic::catch_unwind(|| panic!("not printed")));
+
+        assert!(caught.is_err());
+        assert!(!QUIET.get());
+    }
+}
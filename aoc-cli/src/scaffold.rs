@@ -0,0 +1,537 @@
+//! Generate the files for a new day, and a new year when it is the first day of it
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Sources of the solutions crate
+const REPO_SRC: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc/src");
+
+const REPO_README: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../README.md");
+
+/// Width rustfmt wraps lines at
+const MAX_WIDTH: usize = 100;
+
+/// Where the generated files go
+pub struct Scaffold {
+    src: PathBuf,
+    readme: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(src: impl Into<PathBuf>, readme: impl Into<PathBuf>) -> Scaffold {
+        Scaffold {
+            src: src.into(),
+            readme: readme.into(),
+        }
+    }
+
+    /// Scaffolds into the repository this binary was built from
+    pub fn repository() -> Scaffold {
+        let resolve = |path: &str| fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path));
+
+        Scaffold::new(resolve(REPO_SRC), resolve(REPO_README))
+    }
+
+    /// Creates `aocYYYY/dayNN.rs` with an empty input and registers it, setting up the
+    /// year first if it doesn't exist yet. The README's coverage table gets an empty
+    /// row for the day if it has none.
+    ///
+    /// Returns every file created or changed.
+    pub fn day(&self, year: u16, day: u8, title: &str) -> io::Result<Vec<PathBuf>> {
+        let year_dir = self.src.join(format!("aoc{year}"));
+        let day_path = year_dir.join(format!("day{day:02}.rs"));
+        let input_path = year_dir.join(format!("input/day{day:02}"));
+        let mod_path = year_dir.join("mod.rs");
+
+        if day_path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", day_path.display()),
+            ));
+        }
+
+        let mut changed = vec![];
+
+        let module = if mod_path.exists() {
+            add_day(&fs::read_to_string(&mod_path)?, day)?
+        } else {
+            changed.extend(self.year(year)?);
+            year_module(day)
+        };
+
+        fs::write(&day_path, day_source(year, day, title))?;
+        changed.push(day_path);
+
+        if !input_path.exists() {
+            fs::create_dir_all(year_dir.join("input"))?;
+            fs::write(&input_path, "")?;
+            changed.push(input_path);
+        }
+
+        fs::write(&mod_path, module)?;
+        changed.push(mod_path);
+
+        let readme = fs::read_to_string(&self.readme)?;
+        let with_row = add_day_row(&readme, day)?;
+
+        if with_row != readme {
+            fs::write(&self.readme, with_row)?;
+
+            if !changed.contains(&self.readme) {
+                changed.push(self.readme.clone());
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Creates `aocYYYY` and registers it with the crate and the README, the caller
+    /// writes its module
+    fn year(&self, year: u16) -> io::Result<Vec<PathBuf>> {
+        let year_dir = self.src.join(format!("aoc{year}"));
+
+        let edits = [
+            (
+                self.src.join("lib.rs"),
+                "pub mod aoc",
+                format!("pub mod aoc{year};"),
+            ),
+            (
+                self.src.join("solver.rs"),
+                "    crate::aoc",
+                format!("    crate::aoc{year}::SOLVERS,"),
+            ),
+            (
+                self.src.join("known_answers.rs"),
+                "    (20",
+                format!("    ({year}, crate::aoc{year}::ANSWERS),"),
+            ),
+        ];
+
+        // work out every edit before writing anything, so a failure leaves no half-made year
+        let mut files = vec![];
+
+        for (path, prefix, line) in edits {
+            let text = fs::read_to_string(&path)?;
+
+            files.push((path, insert_sorted(&text, prefix, &line)?));
+        }
+
+        let readme = add_year_column(&fs::read_to_string(&self.readme)?, year)?;
+        files.push((self.readme.clone(), readme));
+
+        fs::create_dir_all(year_dir.join("input"))?;
+
+        files.push((
+            year_dir.join("answers.toml"),
+            format!(
+                "# Answers to AoC {year} for the inputs in ./input, checked by `aoc-cli verify`\n"
+            ),
+        ));
+
+        for (path, text) in files.iter() {
+            fs::write(path, text)?;
+        }
+
+        Ok(files.into_iter().map(|(path, _)| path).collect())
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Inserts `line` among the lines starting with `prefix`, keeping them sorted
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> io::Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|&index| lines[index].starts_with(prefix))
+        .collect();
+
+    let (Some(&first), Some(&last)) = (matching.first(), matching.last()) else {
+        return Err(invalid_data(format!("no lines starting with {prefix:?}")));
+    };
+
+    if lines[first..=last].contains(&line) {
+        return Err(invalid_data(format!("{line:?} is already there")));
+    }
+
+    let index = matching
+        .into_iter()
+        .find(|&index| lines[index] > line)
+        .unwrap_or(last + 1);
+
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `pub mod dayNN;` to a year's module and rewrites its list of solvers
+fn add_day(module: &str, day: u8) -> io::Result<String> {
+    let module = insert_sorted(module, "pub mod day", &format!("pub mod day{day:02};"))?;
+
+    let days: Vec<&str> = module
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub mod ")?.strip_suffix(';'))
+        .collect();
+
+    let start = module
+        .find("pub const SOLVERS")
+        .ok_or_else(|| invalid_data("no SOLVERS in the year's module".to_string()))?;
+    let end = module[start..]
+        .find("];")
+        .map(|end| start + end + "];".len())
+        .ok_or_else(|| invalid_data("SOLVERS is not closed".to_string()))?;
+
+    Ok(format!(
+        "{}{}{}",
+        &module[..start],
+        solvers_source(&days),
+        &module[end..]
+    ))
+}
+
+/// The `SOLVERS` constant as rustfmt lays it out
+fn solvers_source(days: &[&str]) -> String {
+    let solvers: Vec<String> = days.iter().map(|day| format!("&{day}::SOLVER")).collect();
+    let line = format!(
+        "pub const SOLVERS: &[&dyn Solver] = &[{}];",
+        solvers.join(", ")
+    );
+
+    if line.len() <= MAX_WIDTH {
+        return line;
+    }
+
+    let mut source = "pub const SOLVERS: &[&dyn Solver] = &[\n".to_string();
+
+    for solver in solvers {
+        source += &format!("    {solver},\n");
+    }
+
+    source + "];"
+}
+
+/// Width of a coverage table cell, wide enough for both stars
+const CELL_WIDTH: usize = ":star: :star:".len();
+
+/// Line of the README's coverage table header
+fn coverage_header(lines: &[String]) -> io::Result<usize> {
+    lines
+        .iter()
+        .position(|line| line.starts_with("| Day |"))
+        .ok_or_else(|| invalid_data("no coverage table in the README".to_string()))
+}
+
+/// Adds an empty row for the day to the README's coverage table, unless it has one
+fn add_day_row(readme: &str, day: u8) -> io::Result<String> {
+    let mut lines: Vec<String> = readme.lines().map(str::to_string).collect();
+
+    let header = coverage_header(&lines)?;
+    let columns = lines[header].split('|').count() - 3;
+    let rows = lines[header + 2..]
+        .iter()
+        .take_while(|line| line.starts_with('|'))
+        .count();
+
+    let row_day = |line: &String| line.split('|').nth(1)?.trim().parse::<u8>().ok();
+    let mut position = header + 2;
+
+    for line in &lines[header + 2..header + 2 + rows] {
+        match row_day(line) {
+            Some(existing) if existing == day => return Ok(readme.to_string()),
+            Some(existing) if existing > day => break,
+            _ => position += 1,
+        }
+    }
+
+    let cells = format!(" {:CELL_WIDTH$} |", "").repeat(columns);
+    lines.insert(position, format!("| {day:<3} |{cells}"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds an empty column for the year to the README's coverage table
+fn add_year_column(readme: &str, year: u16) -> io::Result<String> {
+    let mut lines: Vec<String> = readme.lines().map(str::to_string).collect();
+
+    let header = coverage_header(&lines)?;
+
+    let years: Vec<u16> = lines[header]
+        .split('|')
+        .filter_map(|cell| cell.trim().parse().ok())
+        .collect();
+
+    if years.contains(&year) {
+        return Ok(readme.to_string());
+    }
+
+    // cells are split on '|', so the first is empty and the second is the day
+    let column = 2 + years.iter().filter(|&&known| known < year).count();
+    let width = CELL_WIDTH;
+
+    for (index, line) in lines.iter_mut().enumerate().skip(header) {
+        if !line.starts_with('|') {
+            break;
+        }
+
+        let cell = match index - header {
+            0 => format!(" {year:<width$} "),
+            1 => format!(" {} ", "-".repeat(width)),
+            _ => format!(" {:width$} ", ""),
+        };
+
+        let mut cells: Vec<&str> = line.split('|').collect();
+        cells.insert(column, &cell);
+
+        *line = cells.join("|");
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Module of a year with only `day` in it
+fn year_module(day: u8) -> String {
+    let day = format!("day{day:02}");
+
+    format!(
+        "pub mod {day};
+
+use crate::solver::Solver;
+
+{}
+
+pub const ANSWERS: &str = include_str!(\"answers.toml\");
+",
+        solvers_source(&[&day])
+    )
+}
+
+fn day_source(year: u16, day: u8, title: &str) -> String {
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    let solver = format!(
+        "pub const SOLVER: Puzzle = Puzzle::new({year}, {day}, \"{title}\", part_one, part_two);"
+    );
+
+    let solver = if solver.len() <= MAX_WIDTH {
+        solver
+    } else {
+        format!(
+            "pub const SOLVER: Puzzle = Puzzle::new(
+    {year},
+    {day},
+    \"{title}\",
+    part_one,
+    part_two,
+);"
+        )
+    };
+
+    format!(
+        r#"use crate::{{Answer, error::Result, solver::Puzzle}};
+
+{solver}
+
+pub fn part_one(_input: &str) -> Result<Answer> {{
+    Ok(Answer::NotImplemented)
+}}
+
+pub fn part_two(_input: &str) -> Result<Answer> {{
+    Ok(Answer::NotImplemented)
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test_offical_example_1() {{
+        let input = r"";
+
+        assert_eq!(part_one(input).unwrap(), Answer::NotImplemented);
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
+    }}
+
+    #[test]
+    fn test_offical_input() {{
+        let input = include_str!("./input/day{day:02}");
+
+        assert_eq!(part_one(input).unwrap(), Answer::NotImplemented);
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
+    }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const LIB: &str = "pub mod answer;
+pub mod aoc2024;
+pub mod aoc2025;
+pub mod error;
+";
+
+    const SOLVER: &str = "const YEARS: &[&[&dyn Solver]] = &[
+    crate::aoc2024::SOLVERS,
+    crate::aoc2025::SOLVERS,
+];
+";
+
+    const KNOWN_ANSWERS: &str = "const YEARS: &[(u16, &str)] = &[
+    (2024, crate::aoc2024::ANSWERS),
+    (2025, crate::aoc2025::ANSWERS),
+];
+";
+
+    const README: &str = "## Solution Coverage
+
+| Day | 2024          | 2025          |
+| --- | ------------- | ------------- |
+| 1   | :star: :star: | :star:        |
+| 2   | :star: :star: |               |
+
+## After
+";
+
+    const MODULE: &str = "pub mod day01;
+pub mod day04;
+
+use crate::solver::Solver;
+
+pub const SOLVERS: &[&dyn Solver] = &[&day01::SOLVER, &day04::SOLVER];
+
+pub const ANSWERS: &str = include_str!(\"answers.toml\");
+";
+
+    fn temp_repo(name: &str) -> (PathBuf, Scaffold) {
+        let root = env::temp_dir().join(format!("aoc-cli-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let src = root.join("src");
+
+        fs::create_dir_all(src.join("aoc2025")).unwrap();
+        fs::write(src.join("lib.rs"), LIB).unwrap();
+        fs::write(src.join("solver.rs"), SOLVER).unwrap();
+        fs::write(src.join("known_answers.rs"), KNOWN_ANSWERS).unwrap();
+        fs::write(src.join("aoc2025/mod.rs"), MODULE).unwrap();
+        fs::write(root.join("README.md"), README).unwrap();
+
+        let scaffold = Scaffold::new(&src, root.join("README.md"));
+
+        (root, scaffold)
+    }
+
+    #[test]
+    fn adds_a_day_to_an_existing_year() {
+        let (root, scaffold) = temp_repo("day");
+        let src = root.join("src");
+
+        let changed = scaffold.day(2025, 3, "Lobby").unwrap();
+
+        assert_eq!(changed.len(), 4);
+        assert_eq!(
+            fs::read_to_string(root.join("README.md")).unwrap(),
+            README.replace(
+                "|               |\n\n",
+                "|               |\n| 3   |               |               |\n\n"
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(src.join("aoc2025/mod.rs")).unwrap(),
+            MODULE
+                .replace("pub mod day04;", "pub mod day03;\npub mod day04;")
+                .replace("&day01::SOLVER, ", "&day01::SOLVER, &day03::SOLVER, ")
+        );
+        assert_eq!(
+            fs::read_to_string(src.join("aoc2025/input/day03")).unwrap(),
+            ""
+        );
+
+        let source = fs::read_to_string(src.join("aoc2025/day03.rs")).unwrap();
+
+        assert!(source.contains("Puzzle::new(2025, 3, \"Lobby\", part_one, part_two);"));
+        assert!(source.contains("include_str!(\"./input/day03\")"));
+
+        assert_eq!(
+            scaffold.day(2025, 3, "Lobby").unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn adds_day_rows_in_order() {
+        let without_first = README.replace("| 1   | :star: :star: | :star:        |\n", "");
+
+        assert_eq!(add_day_row(README, 2).unwrap(), README);
+        assert_eq!(
+            add_day_row(&without_first, 1).unwrap(),
+            README.replace(
+                ":star: :star: | :star:        |",
+                "              |               |"
+            )
+        );
+    }
+
+    #[test]
+    fn sets_up_a_new_year() {
+        let (root, scaffold) = temp_repo("year");
+        let src = root.join("src");
+
+        scaffold.day(2026, 1, "First").unwrap();
+
+        assert!(
+            fs::read_to_string(src.join("lib.rs"))
+                .unwrap()
+                .contains("pub mod aoc2025;\npub mod aoc2026;\npub mod error;")
+        );
+        assert!(
+            fs::read_to_string(src.join("solver.rs"))
+                .unwrap()
+                .contains("    crate::aoc2025::SOLVERS,\n    crate::aoc2026::SOLVERS,\n];")
+        );
+        assert!(
+            fs::read_to_string(src.join("known_answers.rs"))
+                .unwrap()
+                .contains("    (2026, crate::aoc2026::ANSWERS),\n];")
+        );
+        assert_eq!(
+            fs::read_to_string(src.join("aoc2026/mod.rs")).unwrap(),
+            "pub mod day01;\n\nuse crate::solver::Solver;\n\npub const SOLVERS: &[&dyn Solver] = &[&day01::SOLVER];\n\npub const ANSWERS: &str = include_str!(\"answers.toml\");\n"
+        );
+        assert!(src.join("aoc2026/answers.toml").is_file());
+        assert_eq!(
+            fs::read_to_string(root.join("README.md")).unwrap(),
+            "## Solution Coverage
+
+| Day | 2024          | 2025          | 2026          |
+| --- | ------------- | ------------- | ------------- |
+| 1   | :star: :star: | :star:        |               |
+| 2   | :star: :star: |               |               |
+
+## After
+"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn wraps_long_lines_like_rustfmt() {
+        let days: Vec<String> = (1..=6).map(|day| format!("day{day:02}")).collect();
+        let days: Vec<&str> = days.iter().map(String::as_str).collect();
+
+        assert!(solvers_source(&days[..2]).ends_with("&day02::SOLVER];"));
+        assert!(solvers_source(&days).ends_with("    &day06::SOLVER,\n];"));
+
+        let source = day_source(2018, 3, "No Matter How You Slice It, or Dice It");
+
+        assert!(source.contains("Puzzle::new(\n    2018,\n    3,\n"));
+    }
+}
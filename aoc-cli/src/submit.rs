@@ -0,0 +1,530 @@
+//! Submit answers to adventofcode.com, keeping a history of guesses
+//!
+//! Wrong guesses are remembered so the same answer, or one outside the range left
+//! by earlier "too high" and "too low" hints, isn't submitted again. The wait the
+//! site asks for after a wrong answer to a puzzle is also kept, so nothing is sent
+//! for that puzzle before it is over.
+
+use crate::input::Http;
+use advent_of_code::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Wait assumed after a wrong answer when the site doesn't say how long it is
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// Hint given with a wrong answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Response {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was checked
+    TooSoon {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved, or the first part isn't yet
+    WrongLevel,
+    /// Text of a response that isn't any of the above
+    Unrecognised(String),
+}
+
+impl Response {
+    /// Parses the page returned after submitting an answer
+    pub fn parse(html: &str) -> Response {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Response::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+
+            Response::Wrong {
+                hint,
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Response::TooSoon {
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else {
+            Response::Unrecognised(text)
+        }
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "That's the right answer!"),
+            Response::Wrong { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", it is too high")?,
+                    Some(Hint::TooLow) => write!(f, ", it is too low")?,
+                    None => {}
+                }
+
+                match wait {
+                    Some(wait) => write!(f, ". Wait {}s before trying again", wait.as_secs()),
+                    None => Ok(()),
+                }
+            }
+            Response::TooSoon { wait: Some(wait) } => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            Response::TooSoon { wait: None } => write!(f, "Answered too recently"),
+            Response::WrongLevel => {
+                write!(f, "Not the right part, was it already solved?")
+            }
+            Response::Unrecognised(text) => write!(f, "Unrecognised response: {text}"),
+        }
+    }
+}
+
+/// Text of the page's article, where the site puts its response, without any markup
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads waits like "You have 1m 5s left to wait" or "please wait 5 minutes"
+fn parse_wait(text: &str) -> Option<Duration> {
+    let text = text.to_lowercase();
+
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("you have ")? + "you have ".len();
+        let mut seconds = 0;
+
+        for token in text[start..end].split_whitespace() {
+            let (number, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+
+            seconds += match unit {
+                "h" => number * 60 * 60,
+                "m" => number * 60,
+                "s" => number,
+                _ => return None,
+            };
+        }
+
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let start = text.find("please wait ")?;
+    let mut words = text[start + "please wait ".len()..].split_whitespace();
+
+    let number: u64 = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(number * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(number)),
+        _ => None,
+    }
+}
+
+/// A submitted answer and what the site made of it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub correct: bool,
+    pub hint: Option<Hint>,
+}
+
+/// Unix time in seconds before which the site won't accept another answer to a day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Wait {
+    pub year: u16,
+    pub day: u8,
+    pub until: u64,
+}
+
+/// Every answer submitted so far, saved as JSON between runs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub waits: Vec<Wait>,
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    /// Where the history is kept, in `AOC_DATA_DIR` or the user's data directory
+    pub fn default_path() -> Option<PathBuf> {
+        let root = match env::var_os("AOC_DATA_DIR") {
+            Some(root) => PathBuf::from(root),
+            None => dirs::data_dir()?.join("aoc-cli"),
+        };
+
+        Some(root.join("submissions.json"))
+    }
+
+    /// Loads the history, empty when nothing was submitted yet
+    pub fn load(path: &Path) -> io::Result<History> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(io::Error::other),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+
+        fs::write(path, json)
+    }
+
+    fn guesses(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| (guess.year, guess.day, guess.part) == (year, day, part.into()))
+    }
+
+    /// When the site will accept another answer to a day, if it asked to wait
+    pub fn blocked_until(&self, year: u16, day: u8) -> Option<u64> {
+        self.waits
+            .iter()
+            .find(|wait| (wait.year, wait.day) == (year, day))
+            .map(|wait| wait.until)
+    }
+
+    fn wait(&mut self, year: u16, day: u8, until: u64) {
+        self.waits
+            .retain(|wait| (wait.year, wait.day) != (year, day));
+        self.waits.push(Wait { year, day, until });
+    }
+
+    /// Reason not to submit `answer` at unix time `now`, if there is one
+    pub fn refusal(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        now: u64,
+    ) -> Option<String> {
+        if let Answer::Multiline(_) = answer {
+            return Some("multi-line answers have to be read and submitted by hand".to_string());
+        }
+
+        if let Some(guess) = self.guesses(year, day, part).find(|guess| guess.correct) {
+            return Some(format!("the part was already solved with {}", guess.answer));
+        }
+
+        if self
+            .guesses(year, day, part)
+            .any(|guess| *answer == guess.answer.as_str())
+        {
+            return Some(format!("{answer} was already submitted and was wrong"));
+        }
+
+        let value: Option<i128> = answer.to_string().parse().ok();
+
+        for guess in self.guesses(year, day, part) {
+            let (Some(value), Ok(guessed)) = (value, guess.answer.parse::<i128>()) else {
+                continue;
+            };
+
+            match guess.hint {
+                Some(Hint::TooHigh) if value >= guessed => {
+                    return Some(format!("{guessed} was already too high"));
+                }
+                Some(Hint::TooLow) if value <= guessed => {
+                    return Some(format!("{guessed} was already too low"));
+                }
+                _ => {}
+            }
+        }
+
+        match self.blocked_until(year, day) {
+            Some(until) if until > now => Some(format!(
+                "the site asked to wait, try again in {}s",
+                until - now
+            )),
+            _ => None,
+        }
+    }
+
+    /// Records the response to submitting `answer` at unix time `now`
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        response: &Response,
+        now: u64,
+    ) {
+        let guess = |correct, hint| Guess {
+            year,
+            day,
+            part: part.into(),
+            answer: answer.to_string(),
+            correct,
+            hint,
+        };
+
+        match response {
+            Response::Correct => self.guesses.push(guess(true, None)),
+            Response::Wrong { hint, wait } => {
+                self.guesses.push(guess(false, *hint));
+                self.wait(year, day, now + wait.unwrap_or(DEFAULT_WAIT).as_secs());
+            }
+            Response::TooSoon { wait } => {
+                self.wait(year, day, now + wait.unwrap_or(DEFAULT_WAIT).as_secs());
+            }
+            Response::WrongLevel | Response::Unrecognised(_) => {}
+        }
+    }
+}
+
+/// Current unix time in seconds
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Posts the answer to a part and parses the response
+pub fn submit(
+    http: &Http,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> io::Result<Response> {
+    let level = part.to_string();
+    let answer = answer.to_string();
+
+    let html = http.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", &answer)],
+    )?;
+
+    Ok(Response::parse(&html))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::serve_once;
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{text}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            Response::parse(&page(
+                "That's the right answer!  You are <span>one gold star</span> closer."
+            )),
+            Response::Correct
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, \
+                 make sure you're using the full input data.  Please wait one minute before \
+                 trying again. <a href=\"/2023/day/8\">[Return to Day 8]</a>"
+            )),
+            Response::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer.  Because you have guessed incorrectly 4 times \
+                 on this puzzle, please wait 5 minutes before trying again."
+            )),
+            Response::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait."
+            )),
+            Response::TooSoon {
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Response::WrongLevel
+        );
+        assert!(matches!(
+            Response::parse("<html>Puzzle inputs differ by user.</html>"),
+            Response::Unrecognised(_)
+        ));
+    }
+
+    #[test]
+    fn refuses_repeated_and_out_of_range_guesses() {
+        let mut history = History::default();
+        let wrong = |hint| Response::Wrong { hint, wait: None };
+
+        history.record(
+            2023,
+            8,
+            Part::One,
+            &Answer::from(100),
+            &wrong(Some(Hint::TooHigh)),
+            0,
+        );
+        history.record(
+            2023,
+            8,
+            Part::One,
+            &Answer::from(10),
+            &wrong(Some(Hint::TooLow)),
+            0,
+        );
+        history.record(2023, 8, Part::One, &Answer::from(50), &wrong(None), 0);
+
+        let refusal = |answer: i64, now| history.refusal(2023, 8, Part::One, &answer.into(), now);
+
+        assert!(refusal(50, 60).is_some());
+        assert!(refusal(100, 60).is_some());
+        assert!(refusal(120, 60).is_some());
+        assert!(refusal(5, 60).is_some());
+        assert!(
+            refusal(42, 30).is_some(),
+            "still waiting after the last guess"
+        );
+        assert_eq!(refusal(42, 60), None);
+
+        // guesses only count for their own part
+        assert_eq!(
+            history.refusal(2023, 8, Part::Two, &Answer::from(50), 60),
+            None
+        );
+
+        // and waits for their own day
+        assert_eq!(
+            history.refusal(2023, 9, Part::One, &Answer::from(42), 30),
+            None
+        );
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let mut history = History::default();
+
+        history.record(2023, 8, Part::Two, &Answer::from(7), &Response::Correct, 0);
+
+        assert!(
+            history
+                .refusal(2023, 8, Part::Two, &Answer::from(8), 0)
+                .is_some()
+        );
+        assert!(
+            history
+                .refusal(2023, 8, Part::One, &Answer::from("ABC\nDEF"), 0)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn history_round_trips_through_json() {
+        let path = env::temp_dir().join(format!("aoc-cli-history-{}.json", std::process::id()));
+        let mut history = History::load(&path).unwrap();
+
+        history.record(
+            2019,
+            2,
+            Part::One,
+            &Answer::from(1),
+            &Response::TooSoon { wait: None },
+            10,
+        );
+        history.record(
+            2019,
+            2,
+            Part::One,
+            &Answer::from(2),
+            &Response::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: None,
+            },
+            20,
+        );
+        history.save(&path).unwrap();
+
+        assert_eq!(History::load(&path).unwrap(), history);
+        assert_eq!(history.blocked_until(2019, 2), Some(80));
+        assert_eq!(history.waits.len(), 1);
+        assert_eq!(history.guesses.len(), 1);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn submits_the_answer_as_a_form() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<article><p>That's the right answer!</p></article>",
+        );
+        let http = Http::new(&base_url, "secret");
+
+        let response = submit(&http, 2023, 8, Part::Two, &Answer::from(42)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(response, Response::Correct);
+        assert!(request.starts_with("POST /2023/day/8/answer HTTP/1.1\n"));
+        assert!(request.to_lowercase().contains("cookie: session=secret\n"));
+        assert!(request.ends_with("\nlevel=2&answer=42"));
+    }
+}
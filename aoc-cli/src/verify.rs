@@ -0,0 +1,131 @@
+//! Check solutions against the known answers
+
+use crate::input;
+use crate::run::{self, Outcome};
+use advent_of_code::known_answers::{self, KnownAnswers, Verdict};
+use advent_of_code::{Config, Part};
+use std::fs;
+use std::path::Path;
+
+/// Result of checking one part
+enum Check {
+    Verdict(Verdict),
+    Failed(String),
+}
+
+/// Solves every part of the given years that has both an input and a known answer,
+/// printing whether each one passed.
+///
+/// Returns whether every checked part passed. With `strict`, input lines a day
+/// would skip over fail it instead.
+pub fn verify(years: &[u16], inputs: &Path, strict: bool) -> bool {
+    let mut known = vec![];
+
+    for year in years {
+        match known_answers::for_year(*year) {
+            Ok(answers) => known.extend(answers),
+            Err(err) => {
+                eprintln!("Error reading known answers for {}: {}", year, err);
+                return false;
+            }
+        }
+    }
+
+    let (mut passed, mut mismatched, mut failed) = (0, 0, 0);
+
+    for known in known.iter() {
+        let Ok(input) = fs::read_to_string(input::input_path(inputs, known.year, known.day)) else {
+            continue;
+        };
+
+        let mut config = Config::new(known.year, known.day, input);
+        config.strict = strict;
+
+        for part in [Part::One, Part::Two] {
+            if known.get(part).is_none() {
+                continue;
+            }
+
+            config.part = Some(part);
+
+            let outcome = run::quietly(|| run::solve_part(&config));
+
+            let status = match check(known, part, &outcome) {
+                Check::Verdict(Verdict::Unknown) => continue,
+                Check::Verdict(Verdict::Pass) => {
+                    passed += 1;
+                    "pass".to_string()
+                }
+                Check::Verdict(Verdict::Mismatch { expected, actual }) => {
+                    mismatched += 1;
+                    format!("MISMATCH expected {expected}, got {actual}")
+                }
+                Check::Verdict(Verdict::Missing { expected }) => {
+                    mismatched += 1;
+                    format!("MISSING expected {expected}, but the part is not implemented")
+                }
+                Check::Failed(error) => {
+                    failed += 1;
+                    format!("FAIL {error}")
+                }
+            };
+
+            println!(
+                "{} day {:>2} part {}: {}",
+                known.year, known.day, part, status
+            );
+        }
+    }
+
+    if passed + mismatched + failed == 0 {
+        println!("No days have both an input and a known answer");
+        return true;
+    }
+
+    println!();
+    println!("{passed} passed, {mismatched} mismatched, {failed} failed");
+
+    mismatched == 0 && failed == 0
+}
+
+fn check(known: &KnownAnswers, part: Part, outcome: &Outcome) -> Check {
+    match outcome {
+        Outcome::Solved(answer) => Check::Verdict(known.check(part, Some(answer))),
+        Outcome::NotImplemented => Check::Verdict(known.check(part, None)),
+        Outcome::Failed(_) if known.get(part).is_none() => Check::Verdict(Verdict::Unknown),
+        Outcome::Failed(error) => Check::Failed(error.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::Answer;
+
+    #[test]
+    fn failures_only_count_with_a_known_answer() {
+        let known = KnownAnswers {
+            year: 2023,
+            day: 10,
+            part_one: Some(Answer::from(7030)),
+            part_two: None,
+        };
+
+        let failed = Outcome::Failed("boom".to_string());
+
+        assert!(matches!(
+            check(&known, Part::One, &failed),
+            Check::Failed(_)
+        ));
+        assert!(matches!(
+            check(&known, Part::Two, &failed),
+            Check::Verdict(Verdict::Unknown)
+        ));
+    }
+
+    #[test]
+    fn repository_answers_pass() {
+        assert!(verify(&[2017, 2018], Path::new(input::REPO_INPUTS), false));
+        assert!(verify(&[2017, 2018], Path::new(input::REPO_INPUTS), true));
+    }
+}
@@ -0,0 +1,224 @@
+//! Typed answers to AoC puzzles
+//!
+//! Answers keep the type they were computed as, so numeric answers can be
+//! compared as numbers and an unfinished part is never mistaken for an empty
+//! string. Text spelling out a number equals that number, however it's compared.
+//! # Example
+//! ```rust
+//! use advent_of_code::Answer;
+//!
+//! let answer = Answer::from(42u32);
+//!
+//! assert_eq!(answer, 42);
+//! assert_eq!(answer, "42");
+//! assert_eq!(answer, Answer::from("42"));
+//! assert!(answer > Answer::from(-1));
+//! assert_eq!(Answer::NotImplemented.to_string(), "not implemented");
+//! ```
+
+use std::{cmp::Ordering, fmt};
+
+/// Answer to a single part of a day in AoC
+#[derive(Debug, Clone)]
+pub enum Answer {
+    /// A signed integer answer
+    Int(i64),
+    /// An unsigned integer answer
+    Unsigned(u64),
+    /// A single line of text
+    Str(String),
+    /// Text spanning several lines, such as letters drawn in ASCII art
+    Multiline(String),
+    /// The part has not been solved yet
+    NotImplemented,
+}
+
+impl Answer {
+    /// Numeric value of the answer, if it has one
+    fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(i128::from(*n)),
+            Answer::Unsigned(n) => Some(i128::from(*n)),
+            _ => None,
+        }
+    }
+
+    /// Text of the answer, if it is not numeric
+    fn as_text(&self) -> Option<&str> {
+        match self {
+            Answer::Str(s) | Answer::Multiline(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Numeric value of the answer, or of its text when that is a number written
+    /// the way numeric answers are displayed, so equality stays transitive
+    fn as_number(&self) -> Option<i128> {
+        self.as_i128()
+            .or_else(|| self.as_text().and_then(number_in))
+    }
+}
+
+/// Number written in `text` the way numeric answers are displayed, without a plus
+/// sign, leading zeros or `-0`
+fn number_in(text: &str) -> Option<i128> {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+
+    let displayed = !digits.is_empty()
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && (digits == "0" || !digits.starts_with('0'))
+        && text != "-0";
+
+    displayed.then(|| text.parse().ok()).flatten()
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Str(s) | Answer::Multiline(s) => write!(f, "{s}"),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::NotImplemented, Answer::NotImplemented) => true,
+            (Answer::Str(a) | Answer::Multiline(a), Answer::Str(b) | Answer::Multiline(b)) => {
+                a == b
+            }
+            _ => matches!((self.as_number(), other.as_number()), (Some(a), Some(b)) if a == b),
+        }
+    }
+}
+
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Answer) -> Option<Ordering> {
+        if let (Some(a), Some(b)) = (self.as_number(), other.as_number()) {
+            return Some(a.cmp(&b));
+        }
+
+        match (self.as_text(), other.as_text()) {
+            (Some(a), Some(b)) => Some(a.cmp(b)),
+            _ if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Str(s) | Answer::Multiline(s) => s == other,
+            Answer::NotImplemented => false,
+            _ => self.as_i128().is_some() && self.as_i128() == number_in(other),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $target)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.as_i128() == Some(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(Int, i64, i8, i16, i32, i64, isize);
+impl_from_integer!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.contains('\n') {
+            Answer::Multiline(s)
+        } else {
+            Answer::Str(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::from(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_numbers_across_signedness() {
+        assert_eq!(Answer::Int(7), Answer::Unsigned(7));
+        assert!(Answer::Int(-3) < Answer::Unsigned(0));
+        assert!(Answer::Unsigned(u64::MAX) > Answer::Int(i64::MAX));
+    }
+
+    #[test]
+    fn not_implemented_is_not_an_empty_string() {
+        assert_ne!(Answer::NotImplemented, Answer::from(""));
+        assert_ne!(Answer::NotImplemented, "");
+        assert_eq!(Answer::NotImplemented, Answer::NotImplemented);
+    }
+
+    #[test]
+    fn numbers_equal_their_text() {
+        assert_eq!(Answer::from(1), Answer::from("1"));
+        assert_eq!(Answer::from("116"), Answer::from(116u32));
+        assert_eq!(Answer::from(1), "1");
+        assert_eq!(
+            Answer::from(1).partial_cmp(&Answer::from("1")),
+            Some(Ordering::Equal)
+        );
+
+        assert_ne!(Answer::from(1), Answer::from("01"));
+        assert_ne!(Answer::from(1), Answer::from("1x"));
+        assert_ne!(Answer::from(1), "+1");
+        assert_ne!(Answer::from(0), "-0");
+    }
+
+    #[test]
+    fn orders_numeric_text_as_numbers() {
+        assert_eq!(
+            Answer::from(1).partial_cmp(&Answer::from("2")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Answer::from("10").partial_cmp(&Answer::from("9")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Answer::from("abc").partial_cmp(&Answer::from("abd")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(Answer::from(1).partial_cmp(&Answer::from("x")), None);
+        assert_eq!(Answer::from(1).partial_cmp(&Answer::NotImplemented), None);
+    }
+
+    #[test]
+    fn text_with_newlines_is_multiline() {
+        let answer = Answer::from("#..#\n####\n#..#");
+
+        assert!(matches!(answer, Answer::Multiline(_)));
+        assert_eq!(answer.to_string(), "#..#\n####\n#..#");
+    }
+}
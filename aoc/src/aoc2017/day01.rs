@@ -1,25 +1,40 @@
+use crate::Answer;
 use crate::error;
-use crate::Solution;
+use crate::parse;
+use crate::solver::Puzzle;
 
-/// Compute the solution to day 1 of AoC 2017
-pub fn run(input: &str) -> error::Result<Solution> {
-    let parsed_input = parser(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2017, 1, "Inverse Captcha", part_one, part_two)
+    .with_parse(|input| parser(input).map(drop));
 
-    if parsed_input.is_empty() {
-        Err(error::ParsingError::ParseError)?;
-    }
+/// Compute the solution to part one of day 1 of AoC 2017
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let parsed_input = parser(input)?;
+
+    Ok(circular_match_and_sum(&parsed_input, 1).into())
+}
 
-    let part_one = circular_match_and_sum(&parsed_input, 1);
-    let part_two = circular_match_and_sum(&parsed_input, parsed_input.len() / 2);
+/// Compute the solution to part two of day 1 of AoC 2017
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let parsed_input = parser(input)?;
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(circular_match_and_sum(&parsed_input, parsed_input.len() / 2).into())
 }
 
-fn parser(input: &str) -> Vec<u32> {
-    input.chars().filter_map(|c| c.to_digit(10)).collect()
+fn parser(input: &str) -> error::Result<Vec<u32>> {
+    let mut parsed_input = vec![];
+
+    for (index, c) in input.trim().char_indices() {
+        match c.to_digit(10) {
+            Some(digit) => parsed_input.push(digit),
+            None => parse::skip(input, &input.trim_start()[index..], "expected a digit")?,
+        }
+    }
+
+    if parsed_input.is_empty() {
+        Err(error::ParsingError::ParseError)?;
+    }
+
+    Ok(parsed_input)
 }
 
 fn circular_match_and_sum(v: &[u32], offset: usize) -> u32 {
@@ -39,9 +54,20 @@ mod tests {
     fn day_01_2017_matches_offical_result() {
         let input = include_str!("./input/day01");
 
-        let result = run(&input).unwrap();
+        assert_eq!(part_one(input).unwrap(), "1228");
+        assert_eq!(part_two(input).unwrap(), "1238");
+    }
+
+    #[test]
+    fn skips_stray_characters_unless_strict() {
+        assert_eq!(part_one("11 2x2").unwrap(), "3");
+
+        let error = parse::with_strictness(true, || part_one("11 2x2")).unwrap_err();
 
-        assert_eq!(result.part_one, "1228");
-        assert_eq!(result.part_two, "1238");
+        assert!(
+            error
+                .to_string()
+                .contains("line 1, column 3: expected a digit")
+        );
     }
 }
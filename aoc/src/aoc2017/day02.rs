@@ -1,22 +1,21 @@
 use itertools::Itertools;
 
-use crate::{error, Solution};
+use crate::{Answer, error, solver::Puzzle};
 
-/// Compute the solution to day 2 of AoC 2017
-pub fn run(input: &str) -> error::Result<Solution> {
-    let input = parse_input(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2017, 2, "Corruption Checksum", part_one, part_two);
 
-    if input.is_empty() {
-        Err(error::ParsingError::ParseError)?;
-    }
+/// Compute the solution to part one of day 2 of AoC 2017
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let input = parse_input(input)?;
 
-    let checksum = gen_checksum(&input);
-    let sum_of_even_divisons = users_are_odd(&input);
+    Ok(gen_checksum(&input).into())
+}
+
+/// Compute the solution to part two of day 2 of AoC 2017
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let input = parse_input(input)?;
 
-    Ok(Solution {
-        part_one: checksum.to_string(),
-        part_two: sum_of_even_divisons.to_string(),
-    })
+    Ok(users_are_odd(&input).into())
 }
 
 fn gen_checksum(v: &[Vec<u32>]) -> u32 {
@@ -46,19 +45,15 @@ fn users_are_odd(v: &[Vec<u32>]) -> u32 {
                 .map(|(a, b)| {
                     let sum = if a % b == 0 { a / b } else { 0 };
 
-                    if b % a == 0 {
-                        b / a + sum
-                    } else {
-                        sum
-                    }
+                    if b % a == 0 { b / a + sum } else { sum }
                 })
                 .sum::<u32>()
         })
         .sum()
 }
 
-fn parse_input(input: &str) -> Vec<Vec<u32>> {
-    input
+fn parse_input(input: &str) -> error::Result<Vec<Vec<u32>>> {
+    let input: Vec<Vec<u32>> = input
         .lines()
         .filter_map(|line| {
             let record: Vec<u32> = line
@@ -72,7 +67,13 @@ fn parse_input(input: &str) -> Vec<Vec<u32>> {
                 Some(record)
             }
         })
-        .collect()
+        .collect();
+
+    if input.is_empty() {
+        Err(error::ParsingError::ParseError)?;
+    }
+
+    Ok(input)
 }
 
 #[cfg(test)]
@@ -82,9 +83,7 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day02");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "36766");
-        assert_eq!(result.part_two, "261");
+        assert_eq!(part_one(input).unwrap(), "36766");
+        assert_eq!(part_two(input).unwrap(), "261");
     }
 }
@@ -1,105 +1,53 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{error, Solution};
+use crate::{
+    Answer, error,
+    geom::{Direction, Point2},
+    solver::Puzzle,
+};
 
-/// Compute the solution to day 3 of AoC 2017
-pub fn run(input: &str) -> error::Result<Solution> {
-    let input = parser(&input)?;
+pub const SOLVER: Puzzle = Puzzle::new(2017, 3, "Spiral Memory", part_one, part_two);
 
-    let part_one = distance(input);
-    let part_two = memory_walk(input);
+/// Compute the solution to part one of day 3 of AoC 2017
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(distance(input).into())
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct Cell {
-    x: i64,
-    y: i64,
-}
-
-// | -1  1 | 0  1 | 1  1 |
-// | -1  0 |      | 1  0 |
-// | -1 -1 | 0 -1 | 1 -1 |
-#[rustfmt::skip]
-const ADJACENT_CELLS: [Cell; 8] = [
-    Cell { x: -1, y: 1 },  Cell { x: 0, y: 1 },  Cell { x: 1, y: 1 },
-    Cell { x: -1, y: 0 },                        Cell { x: 1, y: 0 },
-    Cell { x: -1, y: -1 }, Cell { x: 0, y: -1 }, Cell { x: 1, y: -1 },
-];
-
-impl Cell {
-    fn new(x: i64, y: i64) -> Cell {
-        Cell { x, y }
-    }
+/// Compute the solution to part two of day 3 of AoC 2017
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
 
-    fn find_adjacent(c: Cell) -> Vec<Cell> {
-        ADJACENT_CELLS.iter().map(|&a| a.shift_by(c)).collect()
-    }
-
-    fn shift_by(self, other: Self) -> Cell {
-        Cell {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
-    }
+    Ok(memory_walk(input).into())
 }
 
-enum Orientation {
-    East,
-    North,
-    West,
-    South,
+/// Square of memory one step to the left of `position`, when facing `direction`
+fn look_left(direction: Direction, position: Point2) -> Point2 {
+    position + direction.turn_left()
 }
 
-impl Orientation {
-    fn look_left(&self, position: Cell) -> Cell {
-        match self {
-            Orientation::East => Cell::new(0, 1).shift_by(position),
-            Orientation::North => Cell::new(-1, 0).shift_by(position),
-            Orientation::West => Cell::new(0, -1).shift_by(position),
-            Orientation::South => Cell::new(1, 0).shift_by(position),
-        }
-    }
-
-    fn move_to(&self, position: Cell) -> Cell {
-        match self {
-            Orientation::East => Cell::new(1, 0).shift_by(position),
-            Orientation::North => Cell::new(0, 1).shift_by(position),
-            Orientation::West => Cell::new(-1, 0).shift_by(position),
-            Orientation::South => Cell::new(0, -1).shift_by(position),
-        }
-    }
-
-    fn orient_left(&self) -> Orientation {
-        match self {
-            Orientation::East => Orientation::North,
-            Orientation::North => Orientation::West,
-            Orientation::West => Orientation::South,
-            Orientation::South => Orientation::East,
-        }
-    }
+/// Sum of the values stored in the squares around `position`
+fn sum_adjacent(matrix: &HashMap<Point2, u64>, position: Point2) -> u64 {
+    position.neighbours8().filter_map(|p| matrix.get(&p)).sum()
 }
 
 fn distance(limit: u64) -> u64 {
-    let mut direction = Orientation::East;
+    let mut direction = Direction::East;
     let mut matrix = HashSet::new();
 
-    matrix.insert(Cell::new(0, 0));
+    matrix.insert(Point2::ORIGIN);
 
-    let mut previous = Cell::new(1, 0);
+    let mut previous = Point2::new(1, 0);
     matrix.insert(previous);
 
     let range = 2..limit;
 
     for _ in range {
-        let look_at = direction.look_left(previous);
+        let look_at = look_left(direction, previous);
 
         if matrix.contains(&look_at) {
-            let next_step = direction.move_to(previous);
+            let next_step = previous + direction;
 
             matrix.insert(next_step);
 
@@ -108,34 +56,31 @@ fn distance(limit: u64) -> u64 {
             matrix.insert(look_at);
 
             previous = look_at;
-            direction = direction.orient_left();
+            direction = direction.turn_left();
         };
     }
 
-    (previous.x.abs() + previous.y.abs()) as u64
+    previous.manhattan(Point2::ORIGIN)
 }
 
 #[allow(clippy::map_entry)]
 fn memory_walk(limit: u64) -> u64 {
-    let mut direction = Orientation::East;
-    let mut matrix: HashMap<Cell, u64> = HashMap::new();
+    let mut direction = Direction::East;
+    let mut matrix: HashMap<Point2, u64> = HashMap::new();
 
-    matrix.insert(Cell::new(0, 0), 1);
+    matrix.insert(Point2::ORIGIN, 1);
 
-    let mut previous = Cell::new(1, 0);
+    let mut previous = Point2::new(1, 0);
     matrix.insert(previous, 1);
 
     let range = 2..limit;
 
     for _ in range {
-        let look_at = direction.look_left(previous);
+        let look_at = look_left(direction, previous);
 
         if matrix.contains_key(&look_at) {
-            let next_step = direction.move_to(previous);
-            let value = Cell::find_adjacent(next_step)
-                .iter()
-                .filter_map(|c| matrix.get(c))
-                .sum();
+            let next_step = previous + direction;
+            let value = sum_adjacent(&matrix, next_step);
 
             matrix.insert(next_step, value);
             previous = next_step;
@@ -144,15 +89,12 @@ fn memory_walk(limit: u64) -> u64 {
                 break;
             }
         } else {
-            let value = Cell::find_adjacent(look_at)
-                .iter()
-                .filter_map(|c| matrix.get(c))
-                .sum();
+            let value = sum_adjacent(&matrix, look_at);
 
             matrix.insert(look_at, value);
 
             previous = look_at;
-            direction = direction.orient_left();
+            direction = direction.turn_left();
 
             if value > limit {
                 break;
@@ -174,9 +116,7 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day03");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "438");
-        assert_eq!(result.part_two, "266330");
+        assert_eq!(part_one(input).unwrap(), "438");
+        assert_eq!(part_two(input).unwrap(), "266330");
     }
 }
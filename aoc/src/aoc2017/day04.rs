@@ -1,22 +1,21 @@
 use std::collections::HashSet;
 
-use crate::{error, Solution};
+use crate::{Answer, error, solver::Puzzle};
 
-/// Compute the solution to day 4 of AoC 2017
-pub fn run(input: &str) -> error::Result<Solution> {
-    let input = parser(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2017, 4, "High-Entropy Passphrases", part_one, part_two);
 
-    if input.is_empty() {
-        Err(error::ParsingError::ParseError)?;
-    }
+/// Compute the solution to part one of day 4 of AoC 2017
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
 
-    let valid_passprase_count = valid_passprase_count(&input);
-    let valid_passprase_count_no_anagrams = valid_passprase_anagrams(&input);
+    Ok(valid_passprase_count(&input).into())
+}
 
-    Ok(Solution {
-        part_one: valid_passprase_count.to_string(),
-        part_two: valid_passprase_count_no_anagrams.to_string(),
-    })
+/// Compute the solution to part two of day 4 of AoC 2017
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
+
+    Ok(valid_passprase_anagrams(&input).into())
 }
 
 fn valid_passprase_anagrams(v: &[Vec<&str>]) -> u32 {
@@ -35,11 +34,7 @@ fn valid_passprase_anagrams(v: &[Vec<&str>]) -> u32 {
                 .collect::<HashSet<Vec<char>>>()
                 .len();
 
-            if set_len == pass_len {
-                1
-            } else {
-                0
-            }
+            if set_len == pass_len { 1 } else { 0 }
         })
         .sum()
 }
@@ -64,8 +59,8 @@ fn valid_passprase_count(v: &[Vec<&str>]) -> u32 {
         .sum()
 }
 
-fn parser(input: &str) -> Vec<Vec<&str>> {
-    input
+fn parser(input: &str) -> error::Result<Vec<Vec<&str>>> {
+    let input: Vec<Vec<&str>> = input
         .lines()
         .filter_map(|line| {
             let record: Vec<&str> = line.split_whitespace().collect();
@@ -76,7 +71,13 @@ fn parser(input: &str) -> Vec<Vec<&str>> {
                 Some(record)
             }
         })
-        .collect()
+        .collect();
+
+    if input.is_empty() {
+        Err(error::ParsingError::ParseError)?;
+    }
+
+    Ok(input)
 }
 
 #[cfg(test)]
@@ -86,9 +87,7 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day04");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "325");
-        assert_eq!(result.part_two, "119");
+        assert_eq!(part_one(input).unwrap(), "325");
+        assert_eq!(part_two(input).unwrap(), "119");
     }
 }
@@ -1,20 +1,25 @@
-use crate::{error, Solution};
+use crate::{Answer, error, solver::Puzzle};
 
-/// Compute the solution to day 5 of AoC 2017
-pub fn run(input: &str) -> error::Result<Solution> {
-    let input = parser(&input);
+pub const SOLVER: Puzzle = Puzzle::new(
+    2017,
+    5,
+    "A Maze of Twisty Trampolines, All Alike",
+    part_one,
+    part_two,
+);
 
-    if input.is_empty() {
-        Err(error::ParsingError::ParseError)?;
-    }
+/// Compute the solution to part one of day 5 of AoC 2017
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
 
-    let part_one = steps_to_exit(&input, |_| 1);
-    let part_two = steps_to_exit(&input, |item| if item >= 3 { -1 } else { 1 });
+    Ok(steps_to_exit(&input, |_| 1).into())
+}
+
+/// Compute the solution to part two of day 5 of AoC 2017
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(steps_to_exit(&input, |item| if item >= 3 { -1 } else { 1 }).into())
 }
 
 fn steps_to_exit<T>(jumps: &[i32], change_jump: T) -> u32
@@ -42,8 +47,14 @@ where
     steps
 }
 
-fn parser(input: &str) -> Vec<i32> {
-    input.lines().filter_map(|line| line.parse().ok()).collect()
+fn parser(input: &str) -> error::Result<Vec<i32>> {
+    let input: Vec<i32> = input.lines().filter_map(|line| line.parse().ok()).collect();
+
+    if input.is_empty() {
+        Err(error::ParsingError::ParseError)?;
+    }
+
+    Ok(input)
 }
 
 #[cfg(test)]
@@ -53,9 +64,7 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day05");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "325922");
-        assert_eq!(result.part_two, "24490906");
+        assert_eq!(part_one(input).unwrap(), "325922");
+        assert_eq!(part_two(input).unwrap(), "24490906");
     }
 }
@@ -1,21 +1,25 @@
 use std::collections::HashMap;
 
-use crate::{error, Solution};
+use crate::{Answer, error, solver::Puzzle};
 
-/// Compute the solution to day 6 of AoC 2017
-pub fn run(input: &str) -> error::Result<Solution> {
-    let input = parser(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2017, 6, "Memory Reallocation", part_one, part_two);
 
-    if input.is_empty() {
-        Err(error::ParsingError::ParseError)?;
-    }
+/// Compute the solution to part one of day 6 of AoC 2017
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
+
+    let (cycles, _) = relocate_until_repeat(&input);
+
+    Ok(cycles.into())
+}
+
+/// Compute the solution to part two of day 6 of AoC 2017
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let input = parser(input)?;
 
-    let (part_one, part_two) = relocate_until_repeat(&input);
+    let (_, loop_size) = relocate_until_repeat(&input);
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(loop_size.into())
 }
 
 fn relocate_until_repeat(banks: &[u32]) -> (u32, u32) {
@@ -74,12 +78,18 @@ fn cycle(banks: &mut Vec<u32>, start: usize) {
     }
 }
 
-fn parser(input: &str) -> Vec<u32> {
-    input
+fn parser(input: &str) -> error::Result<Vec<u32>> {
+    let input: Vec<u32> = input
         .trim()
         .split_whitespace()
         .filter_map(|bank| bank.parse().ok())
-        .collect()
+        .collect();
+
+    if input.is_empty() {
+        Err(error::ParsingError::ParseError)?;
+    }
+
+    Ok(input)
 }
 
 #[cfg(test)]
@@ -89,9 +99,7 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day06");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "12841");
-        assert_eq!(result.part_two, "8038");
+        assert_eq!(part_one(input).unwrap(), "12841");
+        assert_eq!(part_two(input).unwrap(), "8038");
     }
 }
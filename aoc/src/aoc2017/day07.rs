@@ -1,6 +1,6 @@
-use crate::{error, Solution};
+use crate::{Answer, error, search, solver::Puzzle};
 use pest::{self, Parser};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 mod parser {
     use pest_derive::Parser;
@@ -19,35 +19,40 @@ struct Node {
     children: Option<Vec<String>>,
 }
 
-/// Compute the solution to day 7 of AoC 2017
-pub fn run(input: &str) -> error::Result<Solution> {
-    let nodes = parser(&input)?;
+pub const SOLVER: Puzzle = Puzzle::new(2017, 7, "Recursive Circus", part_one, part_two);
 
-    if nodes.is_empty() {
-        Err(error::ParsingError::ParseError)?
-    }
-
-    let _root = find_root_node(&nodes);
+/// Compute the solution to part one of day 7 of AoC 2017
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let nodes = parser(input)?;
 
-    unimplemented!()
+    Ok(find_root_node(&nodes)?.into())
 }
 
-fn find_root_node(nodes: &HashMap<String, Node>) -> String {
-    let mut names: HashSet<&str> = nodes.keys().map(|key| key.as_str()).collect();
+/// Compute the solution to part two of day 7 of AoC 2017
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let nodes = parser(input)?;
 
-    for node in nodes.values() {
-        if let Some(children) = &node.children {
-            for child in children.iter() {
-                names.remove(child.as_str());
-            }
-        }
-    }
+    let _root = find_root_node(&nodes)?;
 
-    names
-        .into_iter()
-        .nth(0)
+    Ok(Answer::NotImplemented)
+}
+
+/// The program at the bottom of the tower, which is the only one not held up by
+/// another and so is first when programs are ordered before the ones they hold
+fn find_root_node(nodes: &HashMap<String, Node>) -> error::Result<String> {
+    let order = search::topological_sort(nodes.keys(), |id| {
+        nodes[id.as_str()]
+            .children
+            .iter()
+            .flatten()
+            .collect::<Vec<_>>()
+    })
+    .ok_or_else(|| anyhow::anyhow!("Programs hold each other up in a cycle"))?;
+
+    Ok(order
+        .first()
         .expect("There should be at least one node")
-        .to_string()
+        .to_string())
 }
 
 fn parser(input: &str) -> error::Result<HashMap<String, Node>> {
@@ -93,23 +98,21 @@ fn parser(input: &str) -> error::Result<HashMap<String, Node>> {
         }
     }
 
+    if nodes.is_empty() {
+        Err(error::ParsingError::ParseError)?
+    }
+
     Ok(nodes)
 }
 
 #[cfg(test)]
 mod tests {
-    // TODO: Solve test and put the offical answer here
-    // use super::*;
-
-    // #[test]
-    // fn matches_offical_result() {
-    //     let input = include_str!("./input/day07");
+    use super::*;
 
-    //     let config = Config {
-    //         day: 1,
-    //         input: input.to_string(),
-    //     };
+    #[test]
+    fn matches_offical_result() {
+        let input = include_str!("./input/day07");
 
-    //     let _result = run(&config.input).unwrap();
-    // }
+        assert_eq!(part_one(input).unwrap(), "mkxke");
+    }
 }
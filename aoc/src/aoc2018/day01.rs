@@ -1,20 +1,20 @@
+use crate::Answer;
 use crate::error;
-use crate::Solution;
+use crate::solver::Puzzle;
 use std::collections::HashSet;
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let modulations = parse(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2018, 1, "Chronal Calibration", part_one, part_two);
 
-    if modulations.is_empty() {
-        Err(anyhow::anyhow!(
-            &"No frequency modulations parsed from input",
-        ))?
-    }
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let modulations = parse(input)?;
+
+    Ok(fold_frequency_modulations(&modulations).into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let modulations = parse(input)?;
 
-    Ok(Solution {
-        part_one: fold_frequency_modulations(&modulations).to_string(),
-        part_two: find_repeating_frequency(&modulations).to_string(),
-    })
+    Ok(find_repeating_frequency(&modulations).into())
 }
 
 fn fold_frequency_modulations(modulations: &[i32]) -> i32 {
@@ -40,11 +40,19 @@ fn find_repeating_frequency(modulations: &[i32]) -> i32 {
     current_frequency
 }
 
-fn parse(input: &str) -> Vec<i32> {
-    input
+fn parse(input: &str) -> error::Result<Vec<i32>> {
+    let modulations: Vec<i32> = input
         .lines()
         .filter_map(|line| line.trim().parse().ok())
-        .collect()
+        .collect();
+
+    if modulations.is_empty() {
+        Err(anyhow::anyhow!(
+            &"No frequency modulations parsed from input",
+        ))?
+    }
+
+    Ok(modulations)
 }
 
 #[cfg(test)]
@@ -55,9 +63,7 @@ mod test {
     fn matches_offical_result() {
         let input = include_str!("./input/day01");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "505");
-        assert_eq!(result.part_two, "72330");
+        assert_eq!(part_one(input).unwrap(), "505");
+        assert_eq!(part_two(input).unwrap(), "72330");
     }
 }
@@ -1,17 +1,21 @@
-use crate::{error, Solution};
+use crate::{Answer, error, solver::Puzzle};
 use std::collections::HashMap;
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let ids = parse(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2018, 2, "Inventory Management System", part_one, part_two);
 
-    if ids.is_empty() {
-        Err(anyhow::anyhow!(&"No inventory ids parsed from input"))?
-    }
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let ids = parse(input)?;
+
+    Ok(compute_checksum(&ids).into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let ids = parse(input)?;
 
-    Ok(Solution {
-        part_one: compute_checksum(&ids).to_string(),
-        part_two: find_common_char(&ids).into_iter().collect(),
-    })
+    Ok(find_common_char(&ids)
+        .into_iter()
+        .collect::<String>()
+        .into())
 }
 
 fn find_common_char(ids: &[&str]) -> Vec<char> {
@@ -72,6 +76,12 @@ fn compute_checksum(ids: &[&str]) -> u32 {
     twice_thrice.0 * twice_thrice.1
 }
 
-fn parse(input: &str) -> Vec<&str> {
-    input.lines().map(|line| line.trim()).collect()
+fn parse(input: &str) -> error::Result<Vec<&str>> {
+    let ids: Vec<&str> = input.lines().map(|line| line.trim()).collect();
+
+    if ids.is_empty() {
+        Err(anyhow::anyhow!(&"No inventory ids parsed from input"))?
+    }
+
+    Ok(ids)
 }
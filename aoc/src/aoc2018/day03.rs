@@ -1,4 +1,4 @@
-use crate::{Solution, error};
+use crate::{Answer, error, solver::Puzzle};
 use pest::{self, Parser};
 use pest_derive::Parser;
 use std::collections::HashMap;
@@ -18,19 +18,20 @@ struct Claim<'a> {
 
 type Cloth = HashMap<(u32, u32), u32>;
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let claims = parse(&input)?;
+pub const SOLVER: Puzzle = Puzzle::new(2018, 3, "No Matter How You Slice It", part_one, part_two);
 
-    if claims.is_empty() {
-        Err(anyhow::anyhow!(&"No claims parsed from input"))?
-    }
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let claims = parse(input)?;
+    let cloth = create_cloth(&claims);
+
+    Ok(count_overlapping_claims(&cloth).into())
+}
 
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let claims = parse(input)?;
     let cloth = create_cloth(&claims);
 
-    Ok(Solution {
-        part_one: count_overlapping_claims(&cloth).to_string(),
-        part_two: find_single_claim(&claims, &cloth).unwrap().to_string(),
-    })
+    Ok(find_single_claim(&claims, &cloth).unwrap().into())
 }
 
 fn create_cloth(claims: &[Claim]) -> Cloth {
@@ -109,6 +110,10 @@ fn parse(input: &'_ str) -> error::Result<Vec<Claim<'_>>> {
         }
     }
 
+    if claims.is_empty() {
+        Err(anyhow::anyhow!(&"No claims parsed from input"))?
+    }
+
     Ok(claims)
 }
 
@@ -1,35 +1,48 @@
-use crate::{error, Solution};
+use crate::{Answer, error, parse, solver::Puzzle};
 use chrono::{NaiveDateTime, Timelike};
 use pest::Parser;
 use std::collections::HashMap;
 use std::str::FromStr;
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let guard_events = parse(input);
+pub const SOLVER: Puzzle = Puzzle::new(2018, 4, "Repose Record", part_one, part_two)
+    .with_parse(|input| parse_non_empty(input).map(drop));
 
-    if guard_events.is_empty() {
-        Err(anyhow::anyhow!(&"No guard events parsed from input"))?
-    }
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let guard_events = parse_non_empty(input)?;
 
     let grouped_guard_events = group_event_by_guard(&guard_events);
     let sleepy = find_sleepy_guard_minute_hash(&grouped_guard_events);
+
+    Ok((sleepy.id * sleepy.ideal_minute).into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let guard_events = parse_non_empty(input)?;
+
+    let grouped_guard_events = group_event_by_guard(&guard_events);
     let consistent_sleeper = find_consistent_sleepy_guard(&grouped_guard_events);
 
-    Ok(Solution {
-        part_one: (sleepy.id * sleepy.ideal_minute).to_string(),
-        part_two: (consistent_sleeper.0 * consistent_sleeper.1).to_string(),
-    })
+    Ok((consistent_sleeper.0 * consistent_sleeper.1).into())
+}
+
+fn parse_non_empty(input: &str) -> error::Result<Vec<GuardEvent>> {
+    let guard_events = parse(input)?;
+
+    if guard_events.is_empty() {
+        Err(anyhow::anyhow!(&"No guard events parsed from input"))?
+    }
+
+    Ok(guard_events)
 }
 
-fn parse(input: &str) -> Vec<GuardEvent> {
-    let mut v = input
-        .lines()
-        .filter_map(|line| GuardEvent::from_str(line.trim()).ok())
-        .collect::<Vec<GuardEvent>>();
+fn parse(input: &str) -> error::Result<Vec<GuardEvent>> {
+    let mut v = parse::lenient_lines(input, |line| {
+        GuardEvent::from_str(line).map_err(|_| "expected a guard event")
+    })?;
 
     v.sort_by(|a, b| a.date.cmp(&b.date));
 
-    v
+    Ok(v)
 }
 
 type GroupedGuardEvents<'a> = HashMap<u32, Vec<&'a GuardEvent>>;
@@ -228,7 +241,7 @@ mod test {
         [1518-11-05 00:55] wakes up
         "#;
 
-        let guard_events = parse(&input);
+        let guard_events = parse(&input).unwrap();
         assert_eq!(guard_events.len(), 17);
 
         let grouped_guard_events = group_event_by_guard(&guard_events);
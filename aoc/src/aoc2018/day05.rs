@@ -1,7 +1,21 @@
-use crate::{error, Solution};
+use crate::{Answer, error, solver::Puzzle};
 use std::collections::HashSet;
 
-pub fn run(input: &str) -> error::Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2018, 5, "Alchemical Reduction", part_one, part_two);
+
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let v = parse(input)?;
+
+    Ok(reduce_polymer(v.iter()).into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let v = parse(input)?;
+
+    Ok(optimial_polymer_length(&v).into())
+}
+
+fn parse(input: &str) -> error::Result<Vec<char>> {
     let v: Vec<char> = input.chars().collect();
 
     if v.is_empty() {
@@ -10,10 +24,7 @@ pub fn run(input: &str) -> error::Result<Solution> {
         ))?
     }
 
-    Ok(Solution {
-        part_one: reduce_polymer(v.iter()).to_string(),
-        part_two: optimial_polymer_length(&v).to_string(),
-    })
+    Ok(v)
 }
 
 fn reduce_polymer<'a>(input: impl Iterator<Item = &'a char>) -> u32 {
@@ -1,32 +1,22 @@
-use crate::{error, Solution};
+use crate::{Answer, error, grid::Grid, solver::Puzzle};
 use std::collections::HashSet;
 use std::ops::Range;
 use std::str::FromStr;
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let coordinates = parse(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2018, 6, "Chronal Coordinates", part_one, part_two);
 
-    if coordinates.is_empty() {
-        Err(anyhow::anyhow!("Insufficent coordinates parsed from input",))?
-    }
-
-    let upper_bound = 1 + coordinates
-        .iter()
-        .map(|c| if c.x > c.y { c.x } else { c.y })
-        .max()
-        .expect("The length of coordinates ought to be greater than 0")
-        as usize;
-
-    let range = 0..upper_bound;
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let coordinates = parse_non_empty(input)?;
+    let range = bounds(&coordinates);
 
     let grid = compute_partial_grid(&coordinates, &range);
 
-    let coordinates_to_ignore = find_coordinates_to_ignore(&grid, &range);
+    let coordinates_to_ignore = find_coordinates_to_ignore(&grid);
 
     let mut regions = vec![0; coordinates.len()];
     for x in range.clone() {
         for y in range.clone() {
-            if let Some(coordinates) = grid[x][y] {
+            if let Some(coordinates) = grid[(x, y)] {
                 if !coordinates_to_ignore.contains(&coordinates) {
                     regions[coordinates] += 1;
                 }
@@ -39,6 +29,13 @@ pub fn run(input: &str) -> error::Result<Solution> {
         .max_by(|a, b| a.cmp(&b))
         .expect("There should be at least one region by this point");
 
+    Ok((*largest_area_size).into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let coordinates = parse_non_empty(input)?;
+    let range = bounds(&coordinates);
+
     let mut optimial_region_size = 0;
     for x in range.clone() {
         for y in range.clone() {
@@ -53,16 +50,22 @@ pub fn run(input: &str) -> error::Result<Solution> {
         }
     }
 
-    Ok(Solution {
-        part_one: largest_area_size.to_string(),
-        part_two: optimial_region_size.to_string(),
-    })
+    Ok(optimial_region_size.into())
 }
 
-type Grid = Vec<Vec<Option<usize>>>;
+fn bounds(coordinates: &[Coordinates]) -> Range<usize> {
+    let upper_bound = 1 + coordinates
+        .iter()
+        .map(|c| if c.x > c.y { c.x } else { c.y })
+        .max()
+        .expect("The length of coordinates ought to be greater than 0")
+        as usize;
+
+    0..upper_bound
+}
 
-fn compute_partial_grid(c: &[Coordinates], range: &Range<usize>) -> Grid {
-    let mut grid = vec![vec![None; range.end]; range.end];
+fn compute_partial_grid(c: &[Coordinates], range: &Range<usize>) -> Grid<Option<usize>> {
+    let mut grid = Grid::new(range.end, range.end, None);
 
     for x in range.clone() {
         for y in range.clone() {
@@ -78,8 +81,8 @@ fn compute_partial_grid(c: &[Coordinates], range: &Range<usize>) -> Grid {
                 distances.iter().filter(|(_, c)| *c == shortest.1).collect();
 
             match closest.len() {
-                1 => grid[x][y] = Some(shortest.0),
-                _ => grid[x][y] = None,
+                1 => grid[(x, y)] = Some(shortest.0),
+                _ => grid[(x, y)] = None,
             };
         }
     }
@@ -87,24 +90,18 @@ fn compute_partial_grid(c: &[Coordinates], range: &Range<usize>) -> Grid {
     grid
 }
 
-#[allow(clippy::ptr_arg)]
-fn find_coordinates_to_ignore(grid: &Grid, range: &Range<usize>) -> HashSet<usize> {
-    range.clone().fold(HashSet::new(), |mut acc, i| {
-        if let Some(c) = grid[0][i] {
-            acc.insert(c);
-        }
-        if let Some(c) = grid[i][0] {
-            acc.insert(c);
-        }
-        if let Some(c) = grid[range.end - 1][i] {
-            acc.insert(c);
-        }
-        if let Some(c) = grid[i][range.end - 1] {
-            acc.insert(c);
-        }
+/// Coordinates with a region touching the edge of the grid, which must be infinite
+fn find_coordinates_to_ignore(grid: &Grid<Option<usize>>) -> HashSet<usize> {
+    let (last_row, last_column) = (grid.height() - 1, grid.width() - 1);
 
-        acc
-    })
+    grid.row(0)
+        .iter()
+        .chain(grid.row(last_row))
+        .chain(grid.column(0))
+        .chain(grid.column(last_column))
+        .flatten()
+        .copied()
+        .collect()
 }
 
 fn parse(input: &str) -> Vec<Coordinates> {
@@ -114,6 +111,16 @@ fn parse(input: &str) -> Vec<Coordinates> {
         .collect()
 }
 
+fn parse_non_empty(input: &str) -> error::Result<Vec<Coordinates>> {
+    let coordinates = parse(input);
+
+    if coordinates.is_empty() {
+        Err(anyhow::anyhow!("Insufficent coordinates parsed from input",))?
+    }
+
+    Ok(coordinates)
+}
+
 struct Coordinates {
     x: i32,
     y: i32,
@@ -165,8 +172,6 @@ mod test {
         assert_eq!(coordinates[5].x, 8);
         assert_eq!(coordinates[5].y, 9);
 
-        let r = run(&input).unwrap();
-
-        assert_eq!(r.part_one, "17");
+        assert_eq!(part_one(input).unwrap(), "17");
     }
 }
@@ -1,36 +1,50 @@
-use crate::{error, Solution};
+use crate::{Answer, error, parse, search, solver::Puzzle};
 use pest::Parser;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let conditionals = parse(&input);
+pub const SOLVER: Puzzle = Puzzle::new(2018, 7, "The Sum of Its Parts", part_one, part_two)
+    .with_parse(|input| parse(input).map(drop));
+
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let conditionals = parse(input)?;
 
     if conditionals.is_empty() {
         Err(anyhow::anyhow!("No conditionals parsed from input"))?
     }
 
-    Ok(Solution {
-        part_one: compute_sequence(&conditionals),
-        part_two: String::new(),
-    })
+    Ok(compute_sequence(&conditionals)?.into())
 }
 
-fn parse(s: &str) -> Vec<Dependency> {
-    s.lines()
-        .filter_map(|line| Dependency::from_str(line.trim()).ok())
-        .collect()
+pub fn part_two(_input: &str) -> error::Result<Answer> {
+    Ok(Answer::NotImplemented)
 }
 
-fn compute_sequence(dependencies: &[Dependency]) -> String {
-    let sequence: VecDeque<char> = VecDeque::new();
-    let mut nodes_without_incoming_edges: Vec<_> = nodes_without_incoming_edges(&dependencies)
-        .into_iter()
-        .collect();
+fn parse(s: &str) -> error::Result<Vec<Dependency>> {
+    let dependencies = parse::lenient_lines(s, |line| {
+        Dependency::from_str(line).map_err(|_| "expected a step dependency")
+    })?;
+
+    Ok(dependencies)
+}
+
+/// Order to do the steps in, taking the first alphabetically when several are ready
+fn compute_sequence(dependencies: &[Dependency]) -> error::Result<String> {
+    let mut consequents: HashMap<char, Vec<char>> = HashMap::new();
 
-    nodes_without_incoming_edges.sort();
+    for dependency in dependencies {
+        consequents
+            .entry(dependency.antecedent)
+            .or_default()
+            .push(dependency.consequent);
+    }
+
+    let sequence = search::topological_sort(nodes_without_incoming_edges(dependencies), |step| {
+        consequents.get(step).cloned().unwrap_or_default()
+    })
+    .ok_or_else(|| anyhow::anyhow!("Steps depend on each other in a cycle"))?;
 
-    sequence.iter().collect()
+    Ok(sequence.into_iter().collect())
 }
 
 fn nodes_without_incoming_edges(dependencies: &[Dependency]) -> HashSet<char> {
@@ -112,7 +126,7 @@ mod test {
         Step F must be finished before step E can begin.
         "#;
 
-        let dependencies = parse(&input);
+        let dependencies = parse(&input).unwrap();
 
         assert_eq!(dependencies.len(), 7);
         assert_eq!(dependencies[0].antecedent, 'C');
@@ -125,6 +139,14 @@ mod test {
         assert!(nodes_without_incoming_edges.contains(&'C'));
         assert!(!nodes_without_incoming_edges.contains(&'A'));
 
-        // assert_eq!(compute_sequence(&conditionals), "CABDFE");
+        assert_eq!(compute_sequence(&dependencies).unwrap(), "CABDFE");
+    }
+
+    #[test]
+    fn matches_offical_result() {
+        let input = include_str!("./input/day07");
+
+        assert_eq!(part_one(input).unwrap(), "GRTAHKLQVYWXMUBCZPIJFEDNSO");
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
     }
 }
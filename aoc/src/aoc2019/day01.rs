@@ -1,13 +1,25 @@
+use crate::Answer;
 use crate::error;
-use crate::Solution;
+use crate::solver::Puzzle;
 
-pub fn run(input: &str) -> error::Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(
+    2019,
+    1,
+    "The Tyranny of the Rocket Equation",
+    part_one,
+    part_two,
+);
+
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let parsed_input = parse(input);
+
+    Ok(module_fuel(&parsed_input).iter().sum::<Module>().into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
     let parsed_input = parse(input);
 
-    Ok(Solution {
-        part_one: part_one(&parsed_input).iter().sum::<Module>().to_string(),
-        part_two: part_two(&parsed_input).to_string(),
-    })
+    Ok(total_fuel(&parsed_input).into())
 }
 
 type Module = i64;
@@ -23,12 +35,12 @@ fn fuel_calc(module: &Module) -> Module {
     module / 3 - 2
 }
 
-fn part_one(modules: &[Module]) -> Vec<Module> {
+fn module_fuel(modules: &[Module]) -> Vec<Module> {
     modules.iter().map(fuel_calc).collect()
 }
 
-fn part_two(modules: &[Module]) -> Module {
-    part_one(modules)
+fn total_fuel(modules: &[Module]) -> Module {
+    module_fuel(modules)
         .iter()
         .map(|fuel_mass| {
             let mut fuel = *fuel_mass;
@@ -51,10 +63,8 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day01");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "3297626");
-        assert_eq!(result.part_two, "4943578");
+        assert_eq!(part_one(input).unwrap(), "3297626");
+        assert_eq!(part_two(input).unwrap(), "4943578");
     }
 
     #[test]
@@ -68,8 +78,8 @@ mod tests {
 
         let parsed_input = parse(input);
 
-        let part_one_solution = part_one(&parsed_input);
-        let part_two_solution = part_two(&[100756]);
+        let part_one_solution = module_fuel(&parsed_input);
+        let part_two_solution = total_fuel(&[100756]);
 
         assert_eq!(part_one_solution.iter().sum::<Module>(), 34241);
         assert_eq!(part_two_solution, 50346);
@@ -1,17 +1,23 @@
+use crate::Answer;
 use crate::error;
-use crate::Solution;
+use crate::solver::Puzzle;
 use intcode::intcode::{IntCodeExecutor, Value};
 
-pub fn run(input: &str) -> error::Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2019, 2, "1202 Program Alarm", part_one, part_two);
+
+pub fn part_one(input: &str) -> error::Result<Answer> {
     let program = parse(input);
 
-    Ok(Solution {
-        part_one: part_one(&program)?.to_string(),
-        part_two: part_two(&program)?.to_string(),
-    })
+    Ok(restore_gravity_assist(&program)?.into())
 }
 
-fn part_one(program: &[Value]) -> error::Result<Value> {
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let program = parse(input);
+
+    Ok(find_noun_and_verb(&program)?.into())
+}
+
+fn restore_gravity_assist(program: &[Value]) -> error::Result<Value> {
     let mut executor = IntCodeExecutor::new(&program);
     executor.modify_with_address(1, 12)?;
     executor.modify_with_address(2, 2)?;
@@ -22,7 +28,7 @@ fn part_one(program: &[Value]) -> error::Result<Value> {
     })
 }
 
-fn part_two(program: &[Value]) -> error::Result<Value> {
+fn find_noun_and_verb(program: &[Value]) -> error::Result<Value> {
     for noun in 0..99 {
         for verb in 0..99 {
             let mut executor = IntCodeExecutor::new(program);
@@ -55,9 +61,7 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day02");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "3716250");
-        assert_eq!(result.part_two, "6472");
+        assert_eq!(part_one(input).unwrap(), "3716250");
+        assert_eq!(part_two(input).unwrap(), "6472");
     }
 }
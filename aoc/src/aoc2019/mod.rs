@@ -2,15 +2,8 @@
 pub mod day01;
 pub mod day02;
 
-use crate::{error, Config, Solution};
+use crate::solver::Solver;
 
-pub fn run(config: &Config) -> error::Result<Solution> {
-    match config.day {
-        1 => day01::run(&config.input),
-        2 => day02::run(&config.input),
-        _ => Err(error::AoCError::UnsupportedDay {
-            day: config.day,
-            year: config.year,
-        }),
-    }
-}
+pub const SOLVERS: &[&dyn Solver] = &[&day01::SOLVER, &day02::SOLVER];
+
+pub const ANSWERS: &str = include_str!("answers.toml");
@@ -1,11 +1,11 @@
+use crate::Answer;
 use crate::error;
-use crate::Solution;
+use crate::solver::Puzzle;
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let expenses: Vec<u32> = input
-        .lines()
-        .filter_map(|line| line.trim().parse::<u32>().ok())
-        .collect();
+pub const SOLVER: Puzzle = Puzzle::new(2020, 1, "Report Repair", part_one, part_two);
+
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let expenses = parse(input);
 
     let mut pairs: Option<(u32, u32)> = None;
 
@@ -18,6 +18,14 @@ pub fn run(input: &str) -> error::Result<Solution> {
         }
     }
 
+    let pairs = pairs.unwrap();
+
+    Ok((pairs.0 * pairs.1).into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let expenses = parse(input);
+
     let mut tri: Option<(u32, u32, u32)> = None;
     'outer2: for (index, x) in expenses.iter().enumerate() {
         for y in expenses[index..].iter() {
@@ -30,13 +38,16 @@ pub fn run(input: &str) -> error::Result<Solution> {
         }
     }
 
-    let pairs = pairs.unwrap();
     let tri = tri.unwrap();
 
-    Ok(Solution {
-        part_one: (pairs.0 * pairs.1).to_string(),
-        part_two: (tri.0 * tri.1 * tri.2).to_string(),
-    })
+    Ok((tri.0 * tri.1 * tri.2).into())
+}
+
+fn parse(input: &str) -> Vec<u32> {
+    input
+        .lines()
+        .filter_map(|line| line.trim().parse::<u32>().ok())
+        .collect()
 }
 
 #[cfg(test)]
@@ -55,19 +66,15 @@ mod tests {
         "
         .trim();
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "514579");
-        assert_eq!(result.part_two, "241861950");
+        assert_eq!(part_one(input).unwrap(), "514579");
+        assert_eq!(part_two(input).unwrap(), "241861950");
     }
 
     #[test]
     fn matches_offical_result() {
         let input = include_str!("./input/day01");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "1010884");
-        assert_eq!(result.part_two, "253928438");
+        assert_eq!(part_one(input).unwrap(), "1010884");
+        assert_eq!(part_two(input).unwrap(), "253928438");
     }
 }
@@ -1,9 +1,13 @@
-use std::convert::TryFrom;
-
+use crate::Answer;
 use crate::error;
-use crate::Solution;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use crate::parse::{self, IResult, integer};
+use crate::solver::Puzzle;
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::{alpha1, anychar, char, space1},
+    sequence::separated_pair,
+};
 
 #[derive(Debug)]
 struct PasswordWithPolicy {
@@ -18,11 +22,6 @@ struct Policy {
     char: char,
 }
 
-static RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?P<min>\d+)-(?P<max>\d+)\s(?P<char>[a-z]):\s(?P<password>[a-z]+)")
-        .expect("Error compiling regex")
-});
-
 impl PasswordWithPolicy {
     fn pass_sled(&self) -> bool {
         let count = self
@@ -51,48 +50,46 @@ impl PasswordWithPolicy {
     }
 }
 
-impl TryFrom<&str> for PasswordWithPolicy {
-    type Error = error::AoCError;
-
-    fn try_from(s: &str) -> error::Result<PasswordWithPolicy> {
-        let captures = RE.captures(s).unwrap();
-
-        let password = &captures["password"];
-        let policy_char = &captures["char"];
-        let min = &captures["min"];
-        let max = &captures["max"];
-
-        Ok(PasswordWithPolicy {
-            password: password.to_string(),
-            policy: Policy {
-                char: policy_char.chars().next().unwrap(),
-                param1: min.parse().unwrap(),
-                param2: max.parse().unwrap(),
+/// `1-3 a: abcde`, a policy followed by a password
+fn password_with_policy(input: &str) -> IResult<'_, PasswordWithPolicy> {
+    let params = separated_pair(integer, char('-'), integer);
+
+    (params, space1, anychar, tag(": "), alpha1)
+        .map(
+            |((param1, param2), _, char, _, password)| PasswordWithPolicy {
+                password: password.to_string(),
+                policy: Policy {
+                    param1,
+                    param2,
+                    char,
+                },
             },
-        })
-    }
+        )
+        .parse(input)
 }
 
-pub fn run(input: &str) -> error::Result<Solution> {
-    let passwords_with_policy: Vec<_> = input
-        .lines()
-        .filter_map(|line| PasswordWithPolicy::try_from(line).ok())
-        .collect();
+pub const SOLVER: Puzzle = Puzzle::new(2020, 2, "Password Philosophy", part_one, part_two);
 
-    let pass_count_seld = passwords_with_policy
+pub fn part_one(input: &str) -> error::Result<Answer> {
+    let pass_count_seld = parse(input)?
         .iter()
         .filter(|password| password.pass_sled())
         .count();
 
-    let pass_count_toboggan = passwords_with_policy
+    Ok(pass_count_seld.into())
+}
+
+pub fn part_two(input: &str) -> error::Result<Answer> {
+    let pass_count_toboggan = parse(input)?
         .iter()
         .filter(|password| password.pass_toboggan())
         .count();
 
-    Ok(Solution {
-        part_one: pass_count_seld.to_string(),
-        part_two: pass_count_toboggan.to_string(),
-    })
+    Ok(pass_count_toboggan.into())
+}
+
+fn parse(input: &str) -> error::Result<Vec<PasswordWithPolicy>> {
+    Ok(parse::parse_lines(input, password_with_policy)?)
 }
 
 #[cfg(test)]
@@ -108,19 +105,15 @@ mod test {
         "
         .trim();
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "2");
-        assert_eq!(result.part_two, "1");
+        assert_eq!(part_one(input).unwrap(), "2");
+        assert_eq!(part_two(input).unwrap(), "1");
     }
 
     #[test]
     fn matches_offical_result() {
         let input = include_str!("./input/day02");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "396");
-        assert_eq!(result.part_two, "428");
+        assert_eq!(part_one(input).unwrap(), "396");
+        assert_eq!(part_two(input).unwrap(), "428");
     }
 }
@@ -1,7 +1,16 @@
+use crate::Answer;
 use crate::error::Result;
-use crate::Solution;
+use crate::solver::Puzzle;
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2021, 1, "Sonar Sweep", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let measurements = parse(input)?;
+
+    Ok(calc_rate_of_increase(&measurements).into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
     let measurements = parse(input)?;
 
     let window_sums: Vec<u32> = measurements
@@ -9,10 +18,7 @@ pub fn run(input: &str) -> Result<Solution> {
         .map(|window| window.iter().sum())
         .collect();
 
-    Ok(Solution {
-        part_one: calc_rate_of_increase(&measurements).to_string(),
-        part_two: calc_rate_of_increase(&window_sums).to_string(),
-    })
+    Ok(calc_rate_of_increase(&window_sums).into())
 }
 
 fn calc_rate_of_increase(measurements: &[u32]) -> u32 {
@@ -72,19 +78,15 @@ mod tests {
         263
         "#;
 
-        let res = run(input).unwrap();
-
-        assert_eq!(res.part_one, "7");
-        assert_eq!(res.part_two, "5");
+        assert_eq!(part_one(input).unwrap(), "7");
+        assert_eq!(part_two(input).unwrap(), "5");
     }
 
     #[test]
     fn matches_offical_result() {
         let input = include_str!("./input/day01");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "1387");
-        assert_eq!(result.part_two, "1362");
+        assert_eq!(part_one(input).unwrap(), "1387");
+        assert_eq!(part_two(input).unwrap(), "1362");
     }
 }
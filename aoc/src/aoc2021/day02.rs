@@ -3,8 +3,9 @@ use regex::Regex;
 use std::str::FromStr;
 
 use crate::{
+    Answer,
     error::{self, Result},
-    Solution,
+    solver::Puzzle,
 };
 
 static MOVEMENT_RE: Lazy<Regex> = Lazy::new(|| {
@@ -38,7 +39,9 @@ impl FromStr for Movement {
     }
 }
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2021, 2, "Dive!", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let movements = parse(input)?;
 
     let (depth, distance) = movements
@@ -49,7 +52,11 @@ pub fn run(input: &str) -> Result<Solution> {
             Movement::Forward(n) => (depth, distance + n),
         });
 
-    let part_one = (depth * distance).to_string();
+    Ok((depth * distance).into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let movements = parse(input)?;
 
     let (_, depth, distance) = movements.iter().fold(
         (0, 0, 0),
@@ -60,12 +67,7 @@ pub fn run(input: &str) -> Result<Solution> {
         },
     );
 
-    let part_two = (depth * distance).to_string();
-
-    Ok(Solution {
-        part_one: part_one,
-        part_two: part_two,
-    })
+    Ok((depth * distance).into())
 }
 
 fn parse(input: &str) -> Result<Vec<Movement>> {
@@ -117,19 +119,15 @@ mod tests {
         forward 2
         ";
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "150");
-        assert_eq!(result.part_two, "900");
+        assert_eq!(part_one(input).unwrap(), "150");
+        assert_eq!(part_two(input).unwrap(), "900");
     }
 
     #[test]
     fn matches_offical_result() {
         let input = include_str!("./input/day02");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "1762050");
-        assert_eq!(result.part_two, "1855892637");
+        assert_eq!(part_one(input).unwrap(), "1762050");
+        assert_eq!(part_two(input).unwrap(), "1855892637");
     }
 }
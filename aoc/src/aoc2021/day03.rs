@@ -1,9 +1,12 @@
 use crate::{
+    Answer,
     error::{self, Result},
-    Solution,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2021, 3, "Binary Diagnostic", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let lines = input.trim().lines().map(|line| line.trim());
     let line_len = lines.clone().next().map_or_else(|| 0, |line| line.len());
 
@@ -35,10 +38,11 @@ pub fn run(input: &str) -> Result<Solution> {
         2,
     )?;
 
-    Ok(Solution {
-        part_one: (gamma * epsilon).to_string(),
-        part_two: "".to_string(),
-    })
+    Ok((gamma * epsilon).into())
+}
+
+pub fn part_two(_input: &str) -> Result<Answer> {
+    Ok(Answer::NotImplemented)
 }
 
 #[cfg(test)]
@@ -63,10 +67,8 @@ mod tests {
 	01010   
 	    ";
 
-        let res = run(input).unwrap();
-
-        assert_eq!(res.part_one, "198");
-        assert_eq!(res.part_two, "230");
+        assert_eq!(part_one(input).unwrap(), "198");
+        assert_eq!(part_two(input).unwrap(), "230");
     }
 
     #[ignore = "todo"]
@@ -74,9 +76,7 @@ mod tests {
     fn matches_offical_result() {
         let input = include_str!("./input/day03");
 
-        let result = run(&input).unwrap();
-
-        assert_eq!(result.part_one, "2724524");
-        assert_eq!(result.part_two, "1855892637");
+        assert_eq!(part_one(input).unwrap(), "2724524");
+        assert_eq!(part_two(input).unwrap(), "1855892637");
     }
 }
@@ -1,26 +1,35 @@
 use itertools::Itertools;
 
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
-    let calorie_groups = parse(input)?;
+pub const SOLVER: Puzzle = Puzzle::new(2022, 1, "Calorie Counting", part_one, part_two);
 
-    let sums: Vec<u32> = calorie_groups
-        .iter()
-        .map(|group| group.iter().sum::<u32>())
-        .collect();
+pub fn part_one(input: &str) -> Result<Answer> {
+    let sums = calorie_sums(input)?;
 
     let largest_sum = sums
         .iter()
         .max()
         .ok_or_else(|| anyhow::anyhow!("expecting at least one calorie group"))?;
 
+    Ok((*largest_sum).into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let sums = calorie_sums(input)?;
+
     let sum_of_top_three: u32 = sums.iter().sorted().rev().take(3).sum();
 
-    Ok(Solution {
-        part_one: largest_sum.to_string(),
-        part_two: sum_of_top_three.to_string(),
-    })
+    Ok(sum_of_top_three.into())
+}
+
+fn calorie_sums(input: &str) -> Result<Vec<u32>> {
+    let calorie_groups = parse(input)?;
+
+    Ok(calorie_groups
+        .iter()
+        .map(|group| group.iter().sum::<u32>())
+        .collect())
 }
 
 fn parse(input: &str) -> Result<Vec<Vec<u32>>> {
@@ -60,19 +69,15 @@ mod tests {
             10000
         ";
 
-        let solution = run(example).unwrap();
-
-        assert_eq!(solution.part_one, "24000");
-        assert_eq!(solution.part_two, "45000");
+        assert_eq!(part_one(example).unwrap(), "24000");
+        assert_eq!(part_two(example).unwrap(), "45000");
     }
 
     #[test]
     fn matches_offical_result() {
         let input = include_str!("./input/day01");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "75622");
-        assert_eq!(solution.part_two, "213159");
+        assert_eq!(part_one(input).unwrap(), "75622");
+        assert_eq!(part_two(input).unwrap(), "213159");
     }
 }
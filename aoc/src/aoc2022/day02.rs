@@ -1,27 +1,37 @@
 use std::str::FromStr;
 
-use crate::{error::AoCError, error::Result, Solution};
+use crate::{Answer, error::AoCError, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
-    let rounds: Vec<Round> = input
-        .trim()
-        .lines()
-        .map(|line| line.trim())
-        .map(|line| line.parse())
-        .collect::<Result<_>>()?;
+pub const SOLVER: Puzzle = Puzzle::new(2022, 2, "Rock Paper Scissors", part_one, part_two);
 
-    let stratagems: Vec<Stratagem> = rounds.iter().map(|line| line.into()).collect();
+pub fn part_one(input: &str) -> Result<Answer> {
+    let rounds = parse(input)?;
 
     let total_score = rounds.iter().map(|round| round.score()).sum::<u32>();
+
+    Ok(total_score.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let rounds = parse(input)?;
+
+    let stratagems: Vec<Stratagem> = rounds.iter().map(|line| line.into()).collect();
+
     let total_score_by_stratagems = stratagems
         .iter()
         .map(|stratagem| stratagem.score())
         .sum::<u32>();
 
-    Ok(Solution {
-        part_one: total_score.to_string(),
-        part_two: total_score_by_stratagems.to_string(),
-    })
+    Ok(total_score_by_stratagems.into())
+}
+
+fn parse(input: &str) -> Result<Vec<Round>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| line.trim())
+        .map(|line| line.parse())
+        .collect()
 }
 
 #[derive(PartialEq, Clone)]
@@ -157,19 +167,15 @@ mod tests {
             C Z
         ";
 
-        let solution = run(example).unwrap();
-
-        assert_eq!(solution.part_one, "15");
-        assert_eq!(solution.part_two, "12");
+        assert_eq!(part_one(example).unwrap(), "15");
+        assert_eq!(part_two(example).unwrap(), "12");
     }
 
     #[test]
     fn matches_offical_results() {
         let input = include_str!("./input/day02");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "9177");
-        assert_eq!(solution.part_two, "12111");
+        assert_eq!(part_one(input).unwrap(), "9177");
+        assert_eq!(part_two(input).unwrap(), "12111");
     }
 }
@@ -1,16 +1,12 @@
 use itertools::Itertools;
 
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 use std::collections::HashSet;
 
-pub fn run(input: &str) -> Result<Solution> {
-    let rucksacks = input
-        .trim()
-        .lines()
-        .map(|line| line.trim().chars().collect::<Vec<char>>());
+pub const SOLVER: Puzzle = Puzzle::new(2022, 3, "Rucksack Reorganization", part_one, part_two);
 
-    let sum_of_common: u32 = rucksacks
-        .clone()
+pub fn part_one(input: &str) -> Result<Answer> {
+    let sum_of_common: u32 = rucksacks(input)
         .map(|mut chars| {
             let right = chars.split_off(chars.len() / 2);
             let left = chars;
@@ -19,7 +15,11 @@ pub fn run(input: &str) -> Result<Solution> {
         })
         .sum();
 
-    let sum_of_groups: u32 = rucksacks
+    Ok(sum_of_common.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let sum_of_groups: u32 = rucksacks(input)
         .batching(|it| {
             if let (Some(one), Some(two), Some(three)) = (it.next(), it.next(), it.next()) {
                 let mut score = 0;
@@ -36,10 +36,14 @@ pub fn run(input: &str) -> Result<Solution> {
         })
         .sum();
 
-    Ok(Solution {
-        part_one: sum_of_common.to_string(),
-        part_two: sum_of_groups.to_string(),
-    })
+    Ok(sum_of_groups.into())
+}
+
+fn rucksacks(input: &str) -> impl Iterator<Item = Vec<char>> + '_ {
+    input
+        .trim()
+        .lines()
+        .map(|line| line.trim().chars().collect::<Vec<char>>())
 }
 
 fn char_score(c: char) -> u32 {
@@ -73,19 +77,15 @@ mod tests {
             CrZsJsPPZsGzwwsLwLmpwMDw
         ";
 
-        let solution = run(example).unwrap();
-
-        assert_eq!(solution.part_one, "157");
-        assert_eq!(solution.part_two, "70");
+        assert_eq!(part_one(example).unwrap(), "157");
+        assert_eq!(part_two(example).unwrap(), "70");
     }
 
     #[test]
     fn matches_offical_results() {
         let input = include_str!("./input/day03");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "8493");
-        assert_eq!(solution.part_two, "2552");
+        assert_eq!(part_one(input).unwrap(), "8493");
+        assert_eq!(part_two(input).unwrap(), "2552");
     }
 }
@@ -1,8 +1,31 @@
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
-    let mut part_one = 0;
-    let mut part_two = 0;
+pub const SOLVER: Puzzle = Puzzle::new(2022, 4, "Camp Cleanup", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let fully_contained = parse(input)?
+        .iter()
+        .filter(|((start_a, end_a), (start_b, end_b))| {
+            (start_a <= start_b && end_a >= end_b) || (start_b <= start_a && end_b >= end_a)
+        })
+        .count();
+
+    Ok(fully_contained.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let overlapping = parse(input)?
+        .iter()
+        .filter(|((start_a, end_a), (start_b, end_b))| end_a >= start_b && start_a <= end_b)
+        .count();
+
+    Ok(overlapping.into())
+}
+
+type Assignment = (u32, u32);
+
+fn parse(input: &str) -> Result<Vec<(Assignment, Assignment)>> {
+    let mut pairs = Vec::new();
 
     for line in input.trim().lines() {
         let (pair1, pair2) = line
@@ -23,21 +46,10 @@ pub fn run(input: &str) -> Result<Solution> {
         let end_a: u32 = end_a.parse()?;
         let end_b: u32 = end_b.parse()?;
 
-        if start_a <= start_b && end_a >= end_b {
-            part_one += 1;
-        } else if start_b <= start_a && end_b >= end_a {
-            part_one += 1;
-        }
-
-        if end_a >= start_b && start_a <= end_b {
-            part_two += 1;
-        }
+        pairs.push(((start_a, end_a), (start_b, end_b)));
     }
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(pairs)
 }
 
 #[cfg(test)]
@@ -55,19 +67,15 @@ mod tests {
             2-6,4-8
         ";
 
-        let solution = run(example).unwrap();
-
-        assert_eq!(solution.part_one, "2");
-        assert_eq!(solution.part_two, "4");
+        assert_eq!(part_one(example).unwrap(), "2");
+        assert_eq!(part_two(example).unwrap(), "4");
     }
 
     #[test]
     fn matches_offical_results() {
         let input = include_str!("./input/day04");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "498");
-        assert_eq!(solution.part_two, "859");
+        assert_eq!(part_one(input).unwrap(), "498");
+        assert_eq!(part_two(input).unwrap(), "859");
     }
 }
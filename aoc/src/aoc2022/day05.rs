@@ -5,22 +5,15 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
-    let (layout, procedures) = input.split_once("\n\n").ok_or_else(|| {
-        anyhow::anyhow!("Expected layout and list of procedures sepereated by empty new line")
-    })?;
-
-    let mut slots = gen_initial_layout(layout);
-    let mut slots2 = slots.clone();
+pub const SOLVER: Puzzle = Puzzle::new(2022, 5, "Supply Stacks", part_one, part_two);
 
-    let procedures = procedures
-        .lines()
-        .map(|line| line.trim().parse())
-        .collect::<Result<Vec<Procedure>>>()?;
+pub fn part_one(input: &str) -> Result<Answer> {
+    let (mut slots, procedures) = parse(input)?;
 
     for procedure in procedures.iter() {
         for _ in 0..procedure.amount {
@@ -29,6 +22,12 @@ pub fn run(input: &str) -> Result<Solution> {
         }
     }
 
+    Ok(top_of_stacks(&slots).into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let (mut slots2, procedures) = parse(input)?;
+
     for procedure in procedures.iter() {
         let mut items = Vec::with_capacity(procedure.amount);
         for _ in 0..procedure.amount {
@@ -42,20 +41,27 @@ pub fn run(input: &str) -> Result<Solution> {
         }
     }
 
-    let part_one = slots
-        .iter()
-        .map(|col| col.last().unwrap())
-        .collect::<String>();
+    Ok(top_of_stacks(&slots2).into())
+}
 
-    let part_two = slots2
+fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vec<Procedure>)> {
+    let (layout, procedures) = input.split_once("\n\n").ok_or_else(|| {
+        anyhow::anyhow!("Expected layout and list of procedures sepereated by empty new line")
+    })?;
+
+    let procedures = procedures
+        .lines()
+        .map(|line| line.trim().parse())
+        .collect::<Result<Vec<Procedure>>>()?;
+
+    Ok((gen_initial_layout(layout), procedures))
+}
+
+fn top_of_stacks(slots: &[Vec<char>]) -> String {
+    slots
         .iter()
         .map(|col| col.last().unwrap())
-        .collect::<String>();
-
-    Ok(Solution {
-        part_one: part_one,
-        part_two: part_two,
-    })
+        .collect::<String>()
 }
 
 fn gen_initial_layout(layout: &str) -> Vec<Vec<char>> {
@@ -125,19 +131,15 @@ move 3 from 1 to 3
 move 2 from 2 to 1
 move 1 from 1 to 2";
 
-        let solution = run(example).unwrap();
-
-        assert_eq!(solution.part_one, "CMZ");
-        assert_eq!(solution.part_two, "MCD");
+        assert_eq!(part_one(example).unwrap(), "CMZ");
+        assert_eq!(part_two(example).unwrap(), "MCD");
     }
 
     #[test]
     fn matches_offical_results() {
         let input = include_str!("./input/day05");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "MQTPGLLDN");
-        assert_eq!(solution.part_two, "LVZPSTTCZ");
+        assert_eq!(part_one(input).unwrap(), "MQTPGLLDN");
+        assert_eq!(part_two(input).unwrap(), "LVZPSTTCZ");
     }
 }
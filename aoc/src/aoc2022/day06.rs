@@ -1,29 +1,26 @@
 use std::collections::HashSet;
 
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
-    let chars_with_index = input.chars().enumerate().collect::<Vec<(usize, char)>>();
+pub const SOLVER: Puzzle = Puzzle::new(2022, 6, "Tuning Trouble", part_one, part_two);
 
-    let part_one = chars_with_index
-        .windows(4)
-        .find_map(|slice| {
-            let s = slice.iter().map(|(_, c)| *c).collect::<HashSet<_>>();
+pub fn part_one(input: &str) -> Result<Answer> {
+    Ok(find_marker(input, 4)?.into())
+}
 
-            if s.len() == 4 {
-                return Some(slice.last().unwrap().0 + 1);
-            }
+pub fn part_two(input: &str) -> Result<Answer> {
+    Ok(find_marker(input, 14)?.into())
+}
 
-            None
-        })
-        .ok_or_else(|| anyhow::anyhow!("unable to find marker character"))?;
+fn find_marker(input: &str, size: usize) -> Result<usize> {
+    let chars_with_index = input.chars().enumerate().collect::<Vec<(usize, char)>>();
 
-    let part_two = chars_with_index
-        .windows(14)
+    let marker = chars_with_index
+        .windows(size)
         .find_map(|slice| {
             let s = slice.iter().map(|(_, c)| *c).collect::<HashSet<_>>();
 
-            if s.len() == 14 {
+            if s.len() == size {
                 return Some(slice.last().unwrap().0 + 1);
             }
 
@@ -31,10 +28,7 @@ pub fn run(input: &str) -> Result<Solution> {
         })
         .ok_or_else(|| anyhow::anyhow!("unable to find marker character"))?;
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(marker)
 }
 
 #[cfg(test)]
@@ -52,15 +46,13 @@ mod tests {
             "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
         ];
 
-        let solutions = examples.map(|s| run(s).unwrap());
-
         assert_eq!(
-            solutions.clone().map(|s| s.part_one),
+            examples.map(|s| part_one(s).unwrap()),
             ["7", "5", "6", "10", "11"]
         );
 
         assert_eq!(
-            solutions.map(|s| s.part_two),
+            examples.map(|s| part_two(s).unwrap()),
             ["19", "23", "23", "29", "26"]
         );
     }
@@ -69,9 +61,7 @@ mod tests {
     fn matches_offical_results() {
         let input = include_str!("./input/day06");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "1953");
-        assert_eq!(solution.part_two, "2301");
+        assert_eq!(part_one(input).unwrap(), "1953");
+        assert_eq!(part_two(input).unwrap(), "2301");
     }
 }
@@ -3,14 +3,21 @@
 use std::{collections::HashMap, path::PathBuf, str::FromStr};
 
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2022, 7, "No Space Left On Device", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let fs = HashMap::<PathBuf, Entity>::new();
 
-    todo!()
+    Ok(Answer::NotImplemented)
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    Ok(Answer::NotImplemented)
 }
 
 enum Entity {
@@ -76,10 +83,8 @@ $ ls
 5626152 d.ext
 7214296 k";
 
-        let solution = run(example).unwrap();
-
-        assert_eq!(solution.part_one, "");
-        assert_eq!(solution.part_two, "");
+        assert_eq!(part_one(example).unwrap(), Answer::NotImplemented);
+        assert_eq!(part_two(example).unwrap(), Answer::NotImplemented);
     }
 
     #[ignore = "todo"]
@@ -87,9 +92,7 @@ $ ls
     fn matches_offical_results() {
         let input = include_str!("./input/day07");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "");
-        assert_eq!(solution.part_two, "");
+        assert_eq!(part_one(input).unwrap(), Answer::NotImplemented);
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
     }
 }
@@ -1,18 +1,20 @@
 use anyhow::anyhow;
 use strum::IntoEnumIterator;
 
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2023, 1, "Trebuchet?!", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let lines: Vec<&str> = input.trim().lines().map(|line| line.trim()).collect();
 
-    let part_one = get_calibrations(&lines, false)?;
-    let part_two = get_calibrations(&lines, true)?;
+    Ok(get_calibrations(&lines, false)?.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let lines: Vec<&str> = input.trim().lines().map(|line| line.trim()).collect();
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(get_calibrations(&lines, true)?.into())
 }
 
 #[derive(strum::IntoStaticStr, strum::EnumIter, Clone, Copy, Debug)]
@@ -143,10 +145,7 @@ zoneight234
     fn matches_offical_result() {
         let input = include_str!("./input/day01");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "53921");
-
-        assert_eq!(solution.part_two, "54676");
+        assert_eq!(part_one(input).unwrap(), "53921");
+        assert_eq!(part_two(input).unwrap(), "54676");
     }
 }
@@ -1,17 +1,25 @@
 use std::str::FromStr;
 
-use anyhow::anyhow;
-use once_cell::sync::Lazy;
-use regex::Regex;
-
-use crate::{error, error::Result, Solution};
-
-pub fn run(input: &str) -> Result<Solution> {
-    let games: Vec<Game> = input
-        .trim()
-        .lines()
-        .map(|line| line.trim().parse())
-        .collect::<Result<_>>()?;
+use nom::{
+    Parser,
+    bytes::complete::tag,
+    character::complete::{alpha1, space1},
+    combinator::map_res,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+};
+
+use crate::{
+    Answer, error,
+    error::Result,
+    parse::{self, IResult, integer},
+    solver::Puzzle,
+};
+
+pub const SOLVER: Puzzle = Puzzle::new(2023, 2, "Cube Conundrum", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let games = parse(input)?;
 
     let part_one: usize = games
         .iter()
@@ -25,16 +33,23 @@ pub fn run(input: &str) -> Result<Solution> {
         .map(|game| game.id)
         .sum();
 
+    Ok(part_one.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let games = parse(input)?;
+
     let part_two: usize = games
         .iter()
         .map(|game| game.smallest_constraint())
         .map(|constraint| constraint.red * constraint.green * constraint.blue)
         .sum();
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(part_two.into())
+}
+
+fn parse(input: &str) -> Result<Vec<Game>> {
+    Ok(parse::parse_lines(input, game)?)
 }
 
 #[derive(Debug)]
@@ -85,54 +100,38 @@ impl Game {
     }
 }
 
-static GAME_RE: Lazy<Regex> =
-    Lazy::new(|| regex::Regex::new(r"Game (?P<id>\d+):").expect("Failed to compile game id regex"));
+/// `Game 1: 3 blue, 4 red; 1 red, 2 green`, with each round separated by `;`
+fn game(input: &str) -> IResult<'_, Game> {
+    let cubes = separated_pair(integer, space1, map_res(alpha1, ColorType::from_str));
+    let round = separated_list1(tag(", "), cubes).map(|cubes| {
+        let mut round = Round {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+
+        for (count, color) in cubes {
+            match color {
+                ColorType::Red => round.red = count,
+                ColorType::Green => round.green = count,
+                ColorType::Blue => round.blue = count,
+            }
+        }
 
-static ROUNDS_RE: Lazy<Regex> = Lazy::new(|| {
-    regex::Regex::new(r"((?P<count>\d+) (?P<color>\w+),?\s?)")
-        .expect("Failed to compile rounds regex")
-});
+        round
+    });
+    let rounds = separated_list1(tag("; "), round);
+
+    preceded(tag("Game "), separated_pair(integer, tag(": "), rounds))
+        .map(|(id, rounds)| Game { id, rounds })
+        .parse(input)
+}
 
 impl FromStr for Game {
     type Err = error::AoCError;
 
     fn from_str(s: &str) -> Result<Self> {
-        let captures = GAME_RE
-            .captures(s)
-            .ok_or_else(|| anyhow!("Failed to parse game: {s}"))?;
-
-        let id = captures.name("id").unwrap().as_str().parse()?;
-
-        let mut rounds = vec![];
-
-        for section in s.split(";") {
-            let mut red = 0;
-            let mut green = 0;
-            let mut blue = 0;
-
-            for capture in ROUNDS_RE.captures_iter(section) {
-                let count = capture.name("count").unwrap().as_str().parse()?;
-
-                let color = ColorType::from_str(capture.name("color").unwrap().as_str())
-                    .map_err(|_| anyhow!("Failed to parse color for seciton: {section}"))?;
-
-                match color {
-                    ColorType::Red => {
-                        red = count;
-                    }
-                    ColorType::Green => {
-                        green = count;
-                    }
-                    ColorType::Blue => {
-                        blue = count;
-                    }
-                }
-            }
-
-            rounds.push(Round { red, green, blue });
-        }
-
-        Ok(Game { id, rounds })
+        Ok(parse::parse_all(s, game)?)
     }
 }
 
@@ -167,19 +166,15 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "8");
-        assert_eq!(solution.part_two, "2286");
+        assert_eq!(part_one(input).unwrap(), "8");
+        assert_eq!(part_two(input).unwrap(), "2286");
     }
 
     #[test]
     fn matches_offical_input() {
         let input = include_str!("./input/day02");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "2256");
-        assert_eq!(solution.part_two, "74229");
+        assert_eq!(part_one(input).unwrap(), "2256");
+        assert_eq!(part_two(input).unwrap(), "74229");
     }
 }
@@ -1,23 +1,19 @@
 use std::{collections::HashSet, usize};
 
-use crate::{error::Result, Solution};
-
-pub fn run(input: &str) -> Result<Solution> {
-    let grid: Grid = input
-        .trim()
-        .lines()
-        .map(|line| line.trim().chars())
-        .map(|chars| chars.map(|c| SchematicItem::from(c)))
-        .map(|row| row.collect())
-        .collect();
-
-    let part_one = solve_part_one(&grid)?;
-    let part_two = solve_part_two(&grid)?;
-
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+use crate::{Answer, error::Result, grid::Grid, solver::Puzzle};
+
+pub const SOLVER: Puzzle = Puzzle::new(2023, 3, "Gear Ratios", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    Ok(solve_part_one(&parse(input)?)?.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    Ok(solve_part_two(&parse(input)?)?.into())
+}
+
+fn parse(input: &str) -> Result<Grid<SchematicItem>> {
+    input.parse()
 }
 
 #[derive(Debug)]
@@ -26,42 +22,28 @@ struct Part {
     positions: HashSet<(usize, usize)>,
 }
 
-fn solve_part_one(grid: &Grid) -> Result<usize> {
+fn solve_part_one(grid: &Grid<SchematicItem>) -> Result<usize> {
     let parts = get_parts(grid)?;
 
     Ok(parts.iter().map(|part| part.number).sum())
 }
 
-fn solve_part_two(grid: &Grid) -> Result<usize> {
+fn solve_part_two(grid: &Grid<SchematicItem>) -> Result<usize> {
     let parts = get_parts(grid)?;
     let mut gear_raitos = vec![];
 
-    for (row_index, row) in grid.iter().enumerate() {
-        for (col_index, col) in row.iter().enumerate() {
-            if let SchematicItem::Symbol('*') = col {
-                let adjacent_cells = (
-                    row_index.saturating_sub(1)..row_index + 2,
-                    col_index.saturating_sub(1)..col_index + 2,
-                );
-
-                let parts: Vec<_> = parts
-                    .iter()
-                    .filter(|part| {
-                        for ri in adjacent_cells.0.clone() {
-                            for ci in adjacent_cells.1.clone() {
-                                if part.positions.contains(&(ri, ci)) {
-                                    return true;
-                                }
-                            }
-                        }
-
-                        false
-                    })
-                    .collect();
-
-                if parts.len() == 2 {
-                    gear_raitos.push(parts[0].number * parts[1].number);
-                }
+    for (position, item) in grid.iter() {
+        if let SchematicItem::Symbol('*') = item {
+            let parts: Vec<_> = parts
+                .iter()
+                .filter(|part| {
+                    grid.neighbours8(position)
+                        .any(|neighbour| part.positions.contains(&neighbour))
+                })
+                .collect();
+
+            if parts.len() == 2 {
+                gear_raitos.push(parts[0].number * parts[1].number);
             }
         }
     }
@@ -69,9 +51,9 @@ fn solve_part_two(grid: &Grid) -> Result<usize> {
     Ok(gear_raitos.iter().sum())
 }
 
-fn get_parts(grid: &Vec<Vec<SchematicItem>>) -> Result<Vec<Part>> {
+fn get_parts(grid: &Grid<SchematicItem>) -> Result<Vec<Part>> {
     let mut parts = vec![];
-    for (row_index, row) in grid.iter().enumerate() {
+    for (row_index, row) in grid.rows().enumerate() {
         let mut num_chars = vec![];
         let mut start: Option<usize> = None;
 
@@ -89,7 +71,7 @@ fn get_parts(grid: &Vec<Vec<SchematicItem>>) -> Result<Vec<Part>> {
                     let n: usize = s.parse()?;
 
                     let positions: HashSet<_> =
-                        (start_col..col_index).map(|c| (row_index, c)).collect();
+                        (start_col..col_index).map(|c| (c, row_index)).collect();
 
                     parts.push(Part {
                         number: n,
@@ -110,7 +92,7 @@ fn get_parts(grid: &Vec<Vec<SchematicItem>>) -> Result<Vec<Part>> {
                 let n: usize = s.parse()?;
 
                 let positions: HashSet<_> =
-                    (start_col..row.len() - 1).map(|c| (row_index, c)).collect();
+                    (start_col..row.len() - 1).map(|c| (c, row_index)).collect();
 
                 parts.push(Part {
                     number: n,
@@ -122,7 +104,12 @@ fn get_parts(grid: &Vec<Vec<SchematicItem>>) -> Result<Vec<Part>> {
     Ok(parts)
 }
 
-fn check_adjacent_cells(grid: &Grid, row_index: usize, start_col: usize, end_col: usize) -> bool {
+fn check_adjacent_cells(
+    grid: &Grid<SchematicItem>,
+    row_index: usize,
+    start_col: usize,
+    end_col: usize,
+) -> bool {
     let adjacent_cells = (
         row_index.saturating_sub(1)..row_index + 2,
         start_col.saturating_sub(1)..end_col + 1,
@@ -131,7 +118,7 @@ fn check_adjacent_cells(grid: &Grid, row_index: usize, start_col: usize, end_col
     let mut found = false;
     for r in adjacent_cells.0 {
         for c in adjacent_cells.1.clone() {
-            if let Some(SchematicItem::Symbol(_)) = grid.get(r).and_then(|row| row.get(c)) {
+            if let Some(SchematicItem::Symbol(_)) = grid.get((c, r)) {
                 found = true;
                 break;
             }
@@ -141,8 +128,6 @@ fn check_adjacent_cells(grid: &Grid, row_index: usize, start_col: usize, end_col
     found
 }
 
-type Grid = Vec<Vec<SchematicItem>>;
-
 enum SchematicItem {
     FullStop,
     Num(char),
@@ -176,19 +161,15 @@ mod tests {
 ...$.*....
 .664.598..";
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "4361");
-        assert_eq!(solution.part_two, "467835");
+        assert_eq!(part_one(input).unwrap(), "4361");
+        assert_eq!(part_two(input).unwrap(), "467835");
     }
 
     #[test]
     fn match_offical_input() {
         let input = include_str!("./input/day03");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "550934");
-        assert_eq!(solution.part_two, "81997870");
+        assert_eq!(part_one(input).unwrap(), "550934");
+        assert_eq!(part_two(input).unwrap(), "81997870");
     }
 }
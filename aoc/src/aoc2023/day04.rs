@@ -9,19 +9,24 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
-    let cards: Vec<Scratchcard> = input
-        .trim()
-        .lines()
-        .map(|line| line.trim().parse())
-        .collect::<Result<_>>()?;
+pub const SOLVER: Puzzle = Puzzle::new(2023, 4, "Scratchcards", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let cards = parse(input)?;
 
     let part_one: usize = cards.iter().map(|card| card.calculate_score()).sum();
 
+    Ok(part_one.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let cards = parse(input)?;
+
     let mut pile_of_cards: HashMap<usize, usize> = HashMap::new();
 
     for card in cards {
@@ -37,10 +42,15 @@ pub fn run(input: &str) -> Result<Solution> {
 
     let part_two: usize = pile_of_cards.iter().map(|(_, v)| v).sum();
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(part_two.into())
+}
+
+fn parse(input: &str) -> Result<Vec<Scratchcard>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| line.trim().parse())
+        .collect()
 }
 
 #[derive(Debug, Clone)]
@@ -163,19 +173,15 @@ Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "13");
-        assert_eq!(solution.part_two, "30");
+        assert_eq!(part_one(input).unwrap(), "13");
+        assert_eq!(part_two(input).unwrap(), "30");
     }
 
     #[test]
     fn match_offical_input() {
         let input = include_str!("./input/day04");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "27845");
-        assert_eq!(solution.part_two, "9496801");
+        assert_eq!(part_one(input).unwrap(), "27845");
+        assert_eq!(part_two(input).unwrap(), "9496801");
     }
 }
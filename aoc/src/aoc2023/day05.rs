@@ -6,9 +6,36 @@ use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use regex::Regex;
 
-use crate::{error::AoCError, error::Result, Solution};
+use crate::{Answer, error::AoCError, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(
+    2023,
+    5,
+    "If You Give A Seed A Fertilizer",
+    part_one,
+    part_two,
+)
+.with_parse(|input| parse(input).map(drop));
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let (seeds, maps) = parse(input)?;
+
+    let part_one = find_lowest_location(&seeds, &maps).unwrap();
+
+    Ok(part_one.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let (seeds, maps) = parse(input)?;
+
+    // get all of the ranges
+
+    let part_two = find_lowest_location_seed_ranges(seeds, &maps).unwrap();
+
+    Ok(part_two.into())
+}
+
+fn parse(input: &str) -> Result<(Vec<isize>, [Vec<Map>; 7])> {
     // spliting on empty new lines
     let sections: Vec<&str> = input.split("\n\n").collect();
 
@@ -38,16 +65,7 @@ pub fn run(input: &str) -> Result<Solution> {
         humidity_location,
     ];
 
-    let part_one = find_lowest_location(&seeds, &maps).unwrap();
-
-    // get all of the ranges
-
-    let part_two = find_lowest_location_seed_ranges(seeds, &maps).unwrap();
-
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok((seeds, maps))
 }
 
 fn find_lowest_location_seed_ranges(seeds: Vec<isize>, list_of_maps: &[Vec<Map>]) -> Option<isize> {
@@ -211,10 +229,8 @@ humidity-to-location map:
 60 56 37
 56 93 4";
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "35");
-        assert_eq!(solution.part_two, "46");
+        assert_eq!(part_one(input).unwrap(), "35");
+        assert_eq!(part_two(input).unwrap(), "46");
     }
 
     #[ignore = "too expensive to run"]
@@ -222,9 +238,7 @@ humidity-to-location map:
     fn match_offical_input() {
         let input = include_str!("./input/day05");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "318728750");
-        assert_eq!(solution.part_two, "37384986");
+        assert_eq!(part_one(input).unwrap(), "318728750");
+        assert_eq!(part_two(input).unwrap(), "37384986");
     }
 }
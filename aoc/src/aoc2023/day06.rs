@@ -1,8 +1,10 @@
 use anyhow::anyhow;
 
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2023, 6, "Wait For It", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let races = parse(input, false)?;
 
     let part_one: u64 = races
@@ -10,14 +12,15 @@ pub fn run(input: &str) -> Result<Solution> {
         .map(|race| race.count_solutions())
         .fold(1, |acc, count| acc * count);
 
+    Ok(part_one.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
     let races = parse(input, true)?;
 
     let part_two: u64 = races.iter().map(|race| race.count_solutions()).sum();
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(part_two.into())
 }
 
 #[derive(Debug)]
@@ -103,19 +106,15 @@ mod tests {
         let input = "Time:      7  15   30
 Distance:  9  40  200";
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "288");
-        assert_eq!(solution.part_two, "71503");
+        assert_eq!(part_one(input).unwrap(), "288");
+        assert_eq!(part_two(input).unwrap(), "71503");
     }
 
     #[test]
     fn match_offical_input() {
         let input = include_str!("./input/day06");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "219849");
-        assert_eq!(solution.part_two, "29432455");
+        assert_eq!(part_one(input).unwrap(), "219849");
+        assert_eq!(part_two(input).unwrap(), "29432455");
     }
 }
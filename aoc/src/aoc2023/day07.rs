@@ -4,45 +4,38 @@ use anyhow::anyhow;
 use itertools::Itertools;
 
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
-    let iter = input
-        .trim()
-        .lines()
-        .map(|line| line.trim().parse::<CardWithBet>());
-
-    let mut hands_without_joker: Vec<HandWithBet> = iter
-        .clone()
-        .map_ok(|cards| HandWithBet::new(cards, false))
-        .collect::<Result<_>>()?;
+pub const SOLVER: Puzzle = Puzzle::new(2023, 7, "Camel Cards", part_one, part_two);
 
-    hands_without_joker.sort();
+pub fn part_one(input: &str) -> Result<Answer> {
+    total_winnings(input, false)
+}
 
-    let part_one = hands_without_joker
-        .iter()
-        .enumerate()
-        .map(|(rank, hand)| hand.bet * (rank as u64 + 1))
-        .sum::<u64>()
-        .to_string();
+pub fn part_two(input: &str) -> Result<Answer> {
+    total_winnings(input, true)
+}
 
-    let mut hands_with_joker: Vec<HandWithBet> = iter
-        .clone()
-        .map_ok(|cards| HandWithBet::new(cards, true))
+fn total_winnings(input: &str, with_joker: bool) -> Result<Answer> {
+    let mut hands: Vec<HandWithBet> = input
+        .trim()
+        .lines()
+        .map(|line| line.trim().parse::<CardWithBet>())
+        .map_ok(|cards| HandWithBet::new(cards, with_joker))
         .collect::<Result<_>>()?;
 
-    hands_with_joker.sort();
+    hands.sort();
 
-    let part_two = hands_with_joker
+    let winnings = hands
         .iter()
         .enumerate()
         .map(|(rank, hand)| hand.bet * (rank as u64 + 1))
-        .sum::<u64>()
-        .to_string();
+        .sum::<u64>();
 
-    Ok(Solution { part_one, part_two })
+    Ok(winnings.into())
 }
 
 #[derive(Debug)]
@@ -259,19 +252,15 @@ KK677 28
 KTJJT 220
 QQQJA 483";
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "6440");
-        assert_eq!(solution.part_two, "5905");
+        assert_eq!(part_one(input).unwrap(), "6440");
+        assert_eq!(part_two(input).unwrap(), "5905");
     }
 
     #[test]
     fn matches_offical_input() {
         let input = include_str!("./input/day07");
 
-        let solution = run(input).unwrap();
-
-        assert_eq!(solution.part_one, "251287184");
-        assert_eq!(solution.part_two, "250757288");
+        assert_eq!(part_one(input).unwrap(), "251287184");
+        assert_eq!(part_two(input).unwrap(), "250757288");
     }
 }
@@ -1,33 +1,41 @@
+use nom::{
+    character::complete::{line_ending, one_of, space0},
+    combinator::map_res,
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair, terminated},
+};
 use num::integer::lcm;
 use std::{collections::HashMap, str::FromStr};
 
-use anyhow::anyhow;
-use once_cell::sync::Lazy;
-use regex::Regex;
-
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    geom::Turn,
+    parse::{self, blank_lines},
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2023, 8, "Haunted Wasteland", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let network = Network::from_str(input)?;
 
     let steps = network.count_steps(Mode::Mortal)?;
-    let ghost_steps = network.count_ghost_steps()?;
 
-    Ok(Solution {
-        part_one: steps.to_string(),
-        part_two: ghost_steps.to_string(),
-    })
+    Ok(steps.into())
 }
 
-static NODE_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(?P<label>\w+) = \((?P<left>\w+), (?P<right>\w+)\)").unwrap());
+pub fn part_two(input: &str) -> Result<Answer> {
+    let network = Network::from_str(input)?;
+
+    let ghost_steps = network.count_ghost_steps()?;
+
+    Ok(ghost_steps.into())
+}
 
 #[derive(Debug)]
 struct Network {
-    instructions: Vec<Direction>,
+    instructions: Vec<Turn>,
     nodes: HashMap<String, Node>,
 }
 
@@ -71,10 +79,10 @@ impl Network {
             }
 
             match direction {
-                Direction::Left => {
+                Turn::Left => {
                     current_node = self.nodes.get(&current_node.left).unwrap();
                 }
-                Direction::Right => {
+                Turn::Right => {
                     current_node = self.nodes.get(&current_node.right).unwrap();
                 }
             }
@@ -86,24 +94,23 @@ impl FromStr for Network {
     type Err = AoCError;
 
     fn from_str(s: &str) -> Result<Self> {
-        let mut instructions = Vec::new();
-        let mut nodes = HashMap::new();
-
-        let sections = s.trim().split("\n\n").collect::<Vec<_>>();
+        let turns = terminated(many1(map_res(one_of("LR"), Turn::try_from)), space0);
+        let nodes = separated_list1(line_ending, delimited(space0, parse::node, space0));
 
-        if sections.len() != 2 {
-            Err(anyhow!("invalid input, expected 2 sections"))?;
-        }
+        let (instructions, nodes) = parse::parse_all(s, separated_pair(turns, blank_lines, nodes))?;
 
-        for c in sections[0].trim().chars() {
-            instructions.push(Direction::try_from(&c)?);
-        }
+        let nodes = nodes
+            .into_iter()
+            .map(|(label, (left, right))| {
+                let node = Node {
+                    label: label.to_string(),
+                    left: left.to_string(),
+                    right: right.to_string(),
+                };
 
-        for line in sections[1].lines() {
-            let node = Node::from_str(line.trim())?;
-
-            nodes.insert(node.label.clone(), node);
-        }
+                (node.label.clone(), node)
+            })
+            .collect();
 
         Ok(Network {
             instructions,
@@ -112,24 +119,6 @@ impl FromStr for Network {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Direction {
-    Left,
-    Right,
-}
-
-impl TryFrom<&char> for Direction {
-    type Error = AoCError;
-
-    fn try_from(s: &char) -> Result<Self> {
-        Ok(match s {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => Err(anyhow!("invalid direction {s}"))?,
-        })
-    }
-}
-
 #[derive(Debug)]
 struct Node {
     label: String,
@@ -137,36 +126,6 @@ struct Node {
     right: String,
 }
 
-impl FromStr for Node {
-    type Err = AoCError;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let captures = NODE_RE
-            .captures(s)
-            .ok_or_else(|| anyhow!("Failed to parse node: {s}"))?;
-
-        let label = captures
-            .name("label")
-            .expect("named group called label")
-            .as_str();
-        let left = captures
-            .name("left")
-            .expect("named group called left")
-            .as_str();
-
-        let right = captures
-            .name("right")
-            .expect("named group called right")
-            .as_str();
-
-        Ok(Node {
-            label: label.to_string(),
-            left: left.to_string(),
-            right: right.to_string(),
-        })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,13 +184,26 @@ XXX = (XXX, XXX)";
         assert_eq!(count, 6);
     }
 
+    #[test]
+    fn test_indented_lines() {
+        let input = "LLR  
+        
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)  
+        ZZZ = (ZZZ, ZZZ)";
+
+        let network = Network::from_str(input).unwrap();
+
+        let count = network.count_steps(Mode::Mortal).unwrap();
+
+        assert_eq!(count, 6);
+    }
+
     #[test]
     fn test_offical_input() {
         let input = include_str!("./input/day08");
 
-        let solution = run(&input).unwrap();
-
-        assert_eq!(solution.part_one, "20777");
-        assert_eq!(solution.part_two, "13289612809129");
+        assert_eq!(part_one(input).unwrap(), "20777");
+        assert_eq!(part_two(input).unwrap(), "13289612809129");
     }
 }
@@ -1,21 +1,20 @@
 use itertools::Itertools;
 
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
-    let readings: Vec<Vec<i64>> = input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.trim()
-                .split_whitespace()
-                .map(|s| s.parse::<i64>().map_err(|err| err.into()))
-                .collect::<Result<_>>()
-        })
-        .collect::<Result<_>>()?;
+pub const SOLVER: Puzzle = Puzzle::new(2023, 9, "Mirage Maintenance", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let readings = parse(input)?;
 
     let part_one: i64 = readings.iter().map(|reading| extrapolate(reading)).sum();
 
+    Ok(part_one.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let readings = parse(input)?;
+
     let part_two: i64 = readings
         .into_iter()
         .map(|reading| {
@@ -25,10 +24,20 @@ pub fn run(input: &str) -> Result<Solution> {
         })
         .sum();
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(part_two.into())
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            line.trim()
+                .split_whitespace()
+                .map(|s| s.parse::<i64>().map_err(|err| err.into()))
+                .collect::<Result<_>>()
+        })
+        .collect()
 }
 
 fn extrapolate(readings: &[i64]) -> i64 {
@@ -62,19 +71,15 @@ mod tests {
 1 3 6 10 15 21
 10 13 16 21 30 45";
 
-        let solution = run(&input).unwrap();
-
-        assert_eq!(solution.part_one, "114");
-        assert_eq!(solution.part_two, "2");
+        assert_eq!(part_one(input).unwrap(), "114");
+        assert_eq!(part_two(input).unwrap(), "2");
     }
 
     #[test]
     fn test_offical_input() {
         let input = include_str!("./input/day09");
 
-        let solution = run(&input).unwrap();
-
-        assert_eq!(solution.part_one, "1684566095");
-        assert_eq!(solution.part_two, "1136");
+        assert_eq!(part_one(input).unwrap(), "1684566095");
+        assert_eq!(part_two(input).unwrap(), "1136");
     }
 }
@@ -1,70 +1,36 @@
 use std::str::FromStr;
 
 use anyhow::anyhow;
-use strum::IntoEnumIterator;
 
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    geom::Direction,
+    grid::Grid,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2023, 10, "Pipe Maze", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let grid = PipeGrid::from_str(input)?;
 
     let len = grid.pipe_len();
 
-    Ok(Solution {
-        part_one: (len / 2).to_string(),
-        part_two: "".to_string(),
-    })
+    Ok((len / 2).into())
+}
+
+pub fn part_two(_input: &str) -> Result<Answer> {
+    Ok(Answer::NotImplemented)
 }
 
 #[derive(Debug)]
 struct PipeGrid {
-    grid: Vec<Vec<Pipe>>,
+    grid: Grid<Pipe>,
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Pos {
-    y: usize,
-    x: usize,
-}
-
-impl Pos {
-    fn next_coodindate(&self, direction: &Direction) -> Option<Pos> {
-        let pos = self;
-        Some(match direction {
-            Direction::South => Pos {
-                y: pos.y + 1,
-                x: pos.x,
-            },
-            Direction::East => Pos {
-                y: pos.y,
-                x: pos.x + 1,
-            },
-            Direction::North => {
-                if pos.y < 1 {
-                    return None;
-                } else {
-                    Pos {
-                        y: pos.y - 1,
-                        x: pos.x,
-                    }
-                }
-            }
-            Direction::West => {
-                if pos.x < 1 {
-                    return None;
-                } else {
-                    Pos {
-                        y: pos.y,
-                        x: pos.x - 1,
-                    }
-                }
-            }
-        })
-    }
-}
+/// Position of a pipe in the grid, as `(x, y)`
+type Pos = (usize, usize);
 
 impl PipeGrid {
     fn pipe_len(&self) -> u64 {
@@ -80,7 +46,7 @@ impl PipeGrid {
 
         loop {
             let next_pipe_direction = pipe.next_pipe_direction(direction).unwrap();
-            let next_pipe_pos = pos.next_coodindate(&next_pipe_direction).unwrap();
+            let next_pipe_pos = self.grid.step(pos, next_pipe_direction).unwrap();
             let next_pipe = self.get_pipe(next_pipe_pos);
 
             count += 1;
@@ -95,40 +61,24 @@ impl PipeGrid {
     }
 
     fn get_pipe(&self, pos: Pos) -> Pipe {
-        self.grid[pos.y][pos.x]
+        self.grid[pos]
     }
 
     fn find_start(&self) -> Option<(Pipe, Pos)> {
-        self.grid.iter().enumerate().find_map(|(y, row)| {
-            let pipe = row
-                .iter()
-                .enumerate()
-                .find(|(_, pipe)| **pipe == Pipe::Start);
-
-            if let Some((x, pipe)) = pipe {
-                Some((*pipe, Pos { x, y }))
-            } else {
-                None
-            }
-        })
+        let pos = self.grid.position(|pipe| *pipe == Pipe::Start)?;
+
+        Some((Pipe::Start, pos))
     }
 
     fn find_connecting_pipes(&self, pos: Pos) -> Vec<(Direction, Pipe, Pos)> {
         let mut connecting_pos = vec![];
 
-        for direction in Direction::iter() {
-            if let Some(next_coodindate) = pos.next_coodindate(&direction) {
-                let pipe = self
-                    .grid
-                    .get(next_coodindate.y)
-                    .and_then(|row| row.get(next_coodindate.x));
-
-                if let Some(pipe) = pipe {
-                    let is_valid = pipe.is_valid_connecting_pipe(direction);
+        for direction in Direction::ALL {
+            if let Some(next_pos) = self.grid.step(pos, direction) {
+                let pipe = self.grid[next_pos];
 
-                    if is_valid {
-                        connecting_pos.push((direction, *pipe, next_coodindate));
-                    }
+                if pipe.is_valid_connecting_pipe(direction) {
+                    connecting_pos.push((direction, pipe, next_pos));
                 }
             }
         }
@@ -141,27 +91,10 @@ impl FromStr for PipeGrid {
     type Err = AoCError;
 
     fn from_str(s: &str) -> Result<Self> {
-        let grid: Vec<Vec<Pipe>> = s
-            .trim()
-            .lines()
-            .map(|line| {
-                line.trim()
-                    .chars()
-                    .map(|c| Pipe::try_from(c))
-                    .collect::<Result<_>>()
-            })
-            .collect::<Result<_>>()?;
-
-        let grid_size = grid.len();
-
-        if grid_size < 2 {
-            return Err(anyhow!("expected square grid of at least size 2x2").into());
-        }
+        let grid: Grid<Pipe> = s.parse()?;
 
-        if grid.iter().all(|row| row.len() != grid_size) {
-            return Err(
-                anyhow!("expected square grid, not all rows match col of {grid_size}").into(),
-            );
+        if grid.width() < 2 || grid.height() < 2 {
+            return Err(anyhow!("expected grid of at least size 2x2").into());
         }
 
         Ok(Self { grid })
@@ -180,14 +113,6 @@ enum Pipe {
     Start,
 }
 
-#[derive(Debug, PartialEq, strum::EnumIter, Clone, Copy)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
 impl Pipe {
     fn is_valid_connecting_pipe(&self, origin: Direction) -> bool {
         let pipe = self;
@@ -284,19 +209,15 @@ L|7||
 -L-J|
 L|-JF";
 
-        let solution = run(&input).unwrap();
-
-        assert_eq!(solution.part_one, "4");
-        assert_eq!(solution.part_two, "");
+        assert_eq!(part_one(input).unwrap(), "4");
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
     }
 
     #[test]
     fn test_offical_input() {
         let input = include_str!("./input/day10");
 
-        let solution = run(&input).unwrap();
-
-        assert_eq!(solution.part_one, "7030");
-        assert_eq!(solution.part_two, "");
+        assert_eq!(part_one(input).unwrap(), "7030");
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
     }
 }
@@ -9,23 +9,19 @@ pub mod day08;
 pub mod day09;
 pub mod day10;
 
-use crate::{error, Config, Solution};
+use crate::solver::Solver;
 
-pub fn run(config: &Config) -> error::Result<Solution> {
-    match config.day {
-        1 => day01::run(&config.input),
-        2 => day02::run(&config.input),
-        3 => day03::run(&config.input),
-        4 => day04::run(&config.input),
-        5 => day05::run(&config.input),
-        6 => day06::run(&config.input),
-        7 => day07::run(&config.input),
-        8 => day08::run(&config.input),
-        9 => day09::run(&config.input),
-        10 => day10::run(&config.input),
-        _ => Err(error::AoCError::UnsupportedDay {
-            day: config.day,
-            year: config.year,
-        }),
-    }
-}
+pub const SOLVERS: &[&dyn Solver] = &[
+    &day01::SOLVER,
+    &day02::SOLVER,
+    &day03::SOLVER,
+    &day04::SOLVER,
+    &day05::SOLVER,
+    &day06::SOLVER,
+    &day07::SOLVER,
+    &day08::SOLVER,
+    &day09::SOLVER,
+    &day10::SOLVER,
+];
+
+pub const ANSWERS: &str = include_str!("answers.toml");
@@ -1,9 +1,35 @@
 use anyhow::anyhow;
 use itertools::Itertools;
 
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2024, 1, "Historian Hysteria", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let (list1, list2) = parse(input)?;
+
+    let part_one: usize = list1
+        .iter()
+        .sorted()
+        .zip(list2.iter().sorted())
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum();
+
+    Ok(part_one.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let (list1, list2) = parse(input)?;
+
+    let part_two: usize = list1
+        .iter()
+        .map(|a| a * list2.iter().filter(|b| *a == **b).count())
+        .sum();
+
+    Ok(part_two.into())
+}
+
+fn parse(input: &str) -> Result<(Vec<usize>, Vec<usize>)> {
     let mut list1 = vec![];
     let mut list2 = vec![];
 
@@ -25,22 +51,7 @@ pub fn run(input: &str) -> Result<Solution> {
         }
     }
 
-    let part_one: usize = list1
-        .iter()
-        .sorted()
-        .zip(list2.iter().sorted())
-        .map(|(a, b)| a.abs_diff(*b))
-        .sum();
-
-    let part_two: usize = list1
-        .iter()
-        .map(|a| a * list2.iter().filter(|b| *a == **b).count())
-        .sum();
-
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok((list1, list2))
 }
 
 #[cfg(test)]
@@ -56,18 +67,14 @@ mod tests {
         3   9
         3   3";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "11");
+        assert_eq!(part_one(input).unwrap(), "11");
     }
 
     #[test]
     fn test_offical_input() {
         let input = include_str!("./input/day01");
 
-        let solution = run(&input).unwrap();
-
-        assert_eq!(solution.part_one, "1722302");
-        assert_eq!(solution.part_two, "20373490");
+        assert_eq!(part_one(input).unwrap(), "1722302");
+        assert_eq!(part_two(input).unwrap(), "20373490");
     }
 }
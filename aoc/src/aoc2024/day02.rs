@@ -3,29 +3,39 @@ use std::str::FromStr;
 use itertools::Itertools;
 
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
-    let reports: Vec<Report> = input
-        .trim()
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .map(|line| Report::from_str(line))
-        .collect::<Result<_>>()?;
+pub const SOLVER: Puzzle = Puzzle::new(2024, 2, "Red-Nosed Reports", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let reports = parse(input)?;
 
     let safe_reports_count = reports.iter().filter(|report| report.is_safe()).count();
 
+    Ok(safe_reports_count.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let reports = parse(input)?;
+
     let damp_safe_reports_count = reports
         .iter()
         .filter(|report| report.is_damp_safe())
         .count();
 
-    Ok(Solution {
-        part_one: safe_reports_count.to_string(),
-        part_two: damp_safe_reports_count.to_string(),
-    })
+    Ok(damp_safe_reports_count.into())
+}
+
+fn parse(input: &str) -> Result<Vec<Report>> {
+    input
+        .trim()
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Report::from_str(line))
+        .collect()
 }
 
 #[derive(Debug)]
@@ -115,18 +125,14 @@ mod tests {
         8 6 4 4 1
         1 3 6 7 9";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "2");
-        assert_eq!(result.part_two, "4");
+        assert_eq!(part_one(input).unwrap(), "2");
+        assert_eq!(part_two(input).unwrap(), "4");
     }
 
     #[test]
     fn offical_input() {
         let input = include_str!("./input/day02");
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "486");
-        assert_eq!(result.part_two, "540");
+        assert_eq!(part_one(input).unwrap(), "486");
+        assert_eq!(part_two(input).unwrap(), "540");
     }
 }
@@ -1,4 +1,4 @@
-use crate::{error::Result, Solution};
+use crate::{Answer, error::Result, solver::Puzzle};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -15,7 +15,9 @@ enum State {
     Dont,
 }
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2024, 3, "Mull It Over", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let part_one: u32 = PART_ONE_RE
         .captures_iter(input)
         .map(|mul| {
@@ -26,6 +28,10 @@ pub fn run(input: &str) -> Result<Solution> {
         })
         .sum();
 
+    Ok(part_one.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
     let mut state = State::Do;
     let mut part_two = 0;
 
@@ -52,10 +58,7 @@ pub fn run(input: &str) -> Result<Solution> {
         }
     }
 
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+    Ok(part_two.into())
 }
 
 #[cfg(test)]
@@ -66,28 +69,22 @@ mod tests {
     fn offical_example_1() {
         let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "161");
-        assert_eq!(result.part_two, "161");
+        assert_eq!(part_one(input).unwrap(), "161");
+        assert_eq!(part_two(input).unwrap(), "161");
     }
 
     #[test]
     fn offical_example_2() {
         let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "161");
-        assert_eq!(result.part_two, "48");
+        assert_eq!(part_one(input).unwrap(), "161");
+        assert_eq!(part_two(input).unwrap(), "48");
     }
 
     #[test]
     fn offical_input() {
         let input = include_str!("./input/day03");
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "156388521");
-        assert_eq!(result.part_two, "75920122");
+        assert_eq!(part_one(input).unwrap(), "156388521");
+        assert_eq!(part_two(input).unwrap(), "75920122");
     }
 }
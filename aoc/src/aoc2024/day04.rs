@@ -1,39 +1,29 @@
-use itertools::Itertools;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use crate::{Answer, error::Result, grid::Grid, solver::Puzzle};
 
-use crate::{Solution, error::Result};
+pub const SOLVER: Puzzle = Puzzle::new(2024, 4, "Ceres Search", part_one, part_two);
 
-type Board = Vec<Vec<char>>;
+pub fn part_one(input: &str) -> Result<Answer> {
+    let board: Grid<char> = input.parse()?;
 
-static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"XMAS|SAMX").expect("Error compiling regex"));
+    let rows: usize = board.rows().map(|row| count_xmas(row.iter())).sum();
+    let cols: usize = board.columns().map(count_xmas).sum();
+    let diagonals: usize = board.diagonals().map(count_xmas).sum();
+    let anti_diagonals: usize = board.anti_diagonals().map(count_xmas).sum();
 
-pub fn run(input: &str) -> Result<Solution> {
-    let board: Board = input
-        .trim()
-        .lines()
-        .map(|line| line.trim().chars().collect_vec())
-        .collect();
+    Ok((rows + cols + diagonals + anti_diagonals).into())
+}
 
-    let rows: usize = board
-        .iter()
-        .map(|row| {
-            let row: String = row.iter().collect();
-            RE.captures_iter(&row).count()
-        })
-        .sum();
+/// Occurrences of XMAS in a line of letters, forwards or backwards
+fn count_xmas<'a>(line: impl Iterator<Item = &'a char>) -> usize {
+    let line: Vec<char> = line.copied().collect();
 
-    let cols: usize = (0..board[0].len())
-        .map(|col| {
-            let column: String = board.iter().map(|row| row[col]).collect();
-            RE.captures_iter(&column).count()
-        })
-        .sum();
+    line.windows(4)
+        .filter(|word| matches!(word, ['X', 'M', 'A', 'S'] | ['S', 'A', 'M', 'X']))
+        .count()
+}
 
-    Ok(Solution {
-        part_one: (rows + cols).to_string(),
-        part_two: "".to_string(),
-    })
+pub fn part_two(_input: &str) -> Result<Answer> {
+    Ok(Answer::NotImplemented)
 }
 
 #[cfg(test)]
@@ -41,7 +31,6 @@ mod tests {
     use super::*;
 
     #[test]
-    #[ignore]
     fn offical_example() {
         let input = "MMMSXXMASM
         MSAMXMSMSA
@@ -54,20 +43,15 @@ mod tests {
         MAMMMXMMMM
         MXMXAXMASX";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "18");
-        assert_eq!(result.part_two, "");
+        assert_eq!(part_one(input).unwrap(), "18");
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
     }
 
     #[test]
-    #[ignore]
     fn offical_input() {
         let input = include_str!("./input/day04");
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "");
-        assert_eq!(result.part_two, "");
+        assert_eq!(part_one(input).unwrap(), 2406);
+        assert_eq!(part_two(input).unwrap(), Answer::NotImplemented);
     }
 }
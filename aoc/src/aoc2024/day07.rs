@@ -1,35 +1,33 @@
 use std::str::FromStr;
 
 use crate::{
+    Answer,
     error::{AoCError, Result},
-    Solution,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
+pub const SOLVER: Puzzle = Puzzle::new(2024, 7, "Bridge Repair", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    Ok(total_calibration(input, false)?.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    Ok(total_calibration(input, true)?.into())
+}
+
+fn total_calibration(input: &str, with_concatenation: bool) -> Result<u64> {
     let calibrations: Vec<Calibration> = input
         .trim()
         .lines()
         .map(|line| line.trim().parse())
         .collect::<Result<_>>()?;
 
-    calibrations[1].is_solvable(false);
-
-    let part_one: u64 = calibrations
+    Ok(calibrations
         .iter()
-        .filter(|cal| cal.is_solvable(false))
+        .filter(|cal| cal.is_solvable(with_concatenation))
         .map(|cal| cal.target)
-        .sum();
-
-    let part_two: u64 = calibrations
-        .iter()
-        .filter(|cal| cal.is_solvable(true))
-        .map(|cal| cal.target)
-        .sum();
-
-    Ok(Solution {
-        part_one: part_one.to_string(),
-        part_two: part_two.to_string(),
-    })
+        .sum())
 }
 
 #[derive(Debug)]
@@ -113,19 +111,15 @@ mod tests {
         21037: 9 7 18 13
         292: 11 6 16 20";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "3749");
-        assert_eq!(result.part_two, "11387");
+        assert_eq!(part_one(input).unwrap(), "3749");
+        assert_eq!(part_two(input).unwrap(), "11387");
     }
 
     #[test]
     fn offical_input() {
         let input = include_str!("./input/day07");
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "303766880536");
-        assert_eq!(result.part_two, "337041851384440");
+        assert_eq!(part_one(input).unwrap(), "303766880536");
+        assert_eq!(part_two(input).unwrap(), "337041851384440");
     }
 }
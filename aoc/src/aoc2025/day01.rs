@@ -1,27 +1,25 @@
 use std::str::FromStr;
 
-use anyhow::anyhow;
-
 use crate::{
-    Solution,
+    Answer,
     error::{AoCError, Result},
+    geom::Turn,
+    solver::Puzzle,
 };
 
-pub fn run(input: &str) -> Result<Solution> {
-    let instructions: Vec<Instruction> = input
-        .trim()
-        .lines()
-        .map(|line| line.trim().parse())
-        .collect::<Result<_>>()?;
+pub const SOLVER: Puzzle = Puzzle::new(2025, 1, "Secret Entrance", part_one, part_two);
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let instructions = parse(input)?;
 
     let mut pos = 50;
     let mut zero_pos_count = 0;
     for Instruction { direction, turn } in instructions.iter() {
         match direction {
-            Direction::R => {
+            Turn::Right => {
                 pos = (pos + turn) % 100;
             }
-            Direction::L => {
+            Turn::Left => {
                 pos = (100 + pos - turn % 100) % 100;
             }
         }
@@ -31,12 +29,18 @@ pub fn run(input: &str) -> Result<Solution> {
         }
     }
 
+    Ok(zero_pos_count.into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let instructions = parse(input)?;
+
     // I'm sure there must be a smarter way of doing this, but this works
     let mut zero_click_count = 0;
     let mut dial = 50;
     for Instruction { direction, turn } in instructions.iter() {
         for _ in 0..*turn {
-            if let Direction::R = direction {
+            if let Turn::Right = direction {
                 dial += 1;
             } else {
                 dial -= 1;
@@ -56,22 +60,21 @@ pub fn run(input: &str) -> Result<Solution> {
         }
     }
 
-    Ok(Solution {
-        part_one: zero_pos_count.to_string(),
-        part_two: zero_click_count.to_string(),
-    })
+    Ok(zero_click_count.into())
 }
 
-#[derive(Debug)]
-struct Instruction {
-    direction: Direction,
-    turn: i32,
+fn parse(input: &str) -> Result<Vec<Instruction>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| line.trim().parse())
+        .collect()
 }
 
 #[derive(Debug)]
-enum Direction {
-    L,
-    R,
+struct Instruction {
+    direction: Turn,
+    turn: i32,
 }
 
 impl FromStr for Instruction {
@@ -79,20 +82,10 @@ impl FromStr for Instruction {
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         let mut chars = s.chars();
-        let direction = chars.next();
-        let movement: i32 = String::from_iter(chars).parse()?;
-
-        Ok(match direction {
-            Some('L') => Self {
-                direction: Direction::L,
-                turn: movement,
-            },
-            Some('R') => Self {
-                direction: Direction::R,
-                turn: movement,
-            },
-            _ => return Err(anyhow!("expected 'L' or 'R' char at start of each line").into()),
-        })
+        let direction = Turn::try_from(chars.next().unwrap_or_default())?;
+        let turn: i32 = String::from_iter(chars).parse()?;
+
+        Ok(Self { direction, turn })
     }
 }
 
@@ -113,10 +106,8 @@ mod tests {
         R14
         L82";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "3");
-        assert_eq!(result.part_two, "6");
+        assert_eq!(part_one(input).unwrap(), "3");
+        assert_eq!(part_two(input).unwrap(), "6");
     }
 
     #[test]
@@ -127,19 +118,15 @@ mod tests {
         R100
         L175";
 
-        let result = run(input).unwrap();
-
-        assert_eq!(result.part_one, "2");
-        assert_eq!(result.part_two, "6");
+        assert_eq!(part_one(input).unwrap(), "2");
+        assert_eq!(part_two(input).unwrap(), "6");
     }
 
     #[test]
     fn test_offical_input() {
         let input = include_str!("./input/day01");
 
-        let solution = run(&input).unwrap();
-
-        assert_eq!(solution.part_one, "989");
-        assert_eq!(solution.part_two, "5941");
+        assert_eq!(part_one(input).unwrap(), "989");
+        assert_eq!(part_two(input).unwrap(), "5941");
     }
 }
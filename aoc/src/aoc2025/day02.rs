@@ -1,13 +1,17 @@
-use crate::{Solution, error::Result};
+use crate::{Answer, error::Result, solver::Puzzle};
 
-pub fn run(input: &str) -> Result<Solution> {
-    unimplemented!()
+pub const SOLVER: Puzzle = Puzzle::new(2025, 2, "Gift Shop", part_one, part_two);
+
+pub fn part_one(_input: &str) -> Result<Answer> {
+    Ok(Answer::NotImplemented)
+}
+
+pub fn part_two(_input: &str) -> Result<Answer> {
+    Ok(Answer::NotImplemented)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn test_offical_example_1() {}
 
@@ -2,6 +2,8 @@
 
 use intcode::error::Error as IntCodeError;
 
+use crate::Part;
+
 use thiserror::Error;
 
 /// Convenience Result type
@@ -14,11 +16,30 @@ pub enum AoCError {
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
     /// Error when parsing provided input
-    #[error("test input parsing error")]
+    #[error("invalid input: {0}")]
     ParseError(#[from] ParsingError),
+    /// Error when parsing the input of a day, saying which day it was
+    #[error("invalid input for day {day} of {year}: {error}")]
+    InvalidInput {
+        year: u16,
+        day: u8,
+        #[source]
+        error: ParsingError,
+    },
     /// Error when the day is not supported or does not exist
     #[error("unsupported year (expected {year:?} and/or day {day:?})")]
     UnsupportedDay { year: u16, day: u8 },
+    /// Error when the day exists but the requested part has not been solved yet
+    #[error("part {part} of day {day} of {year} is not implemented yet")]
+    NotImplemented { year: u16, day: u8, part: Part },
+    /// Error when a solver panicked instead of returning an error
+    #[error("part {part} of day {day} of {year} panicked: {message}")]
+    Panic {
+        year: u16,
+        day: u8,
+        part: Part,
+        message: String,
+    },
     /// An error coming from intcode execution
     #[error("an IntCode Execution Error")]
     IntCodeError(#[from] IntCodeError),
@@ -35,15 +56,42 @@ pub enum ParsingError {
     #[error(transparent)]
     RegexParseError(#[from] regex::Error),
 
-    #[error("test input parsing error")]
+    #[error(transparent)]
+    TomlParseError(#[from] toml::de::Error),
+
+    #[error("input is not in the expected format")]
     ParseError,
+
+    /// Input that doesn't match what a parser expected, at a line and column
+    /// counting from 1, shown with the text of the line
+    #[error("line {line}, column {column}: {message}\n{}", excerpt(*.line, *.column, .text))]
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+        text: String,
+    },
+}
+
+/// A line of input with its number, and a caret under the column. Tabs before the
+/// column are kept so the caret lines up however wide they're shown.
+fn excerpt(line: usize, column: usize, text: &str) -> String {
+    let gutter = " ".repeat(line.to_string().len());
+    let padding: String = text
+        .chars()
+        .chain(std::iter::repeat(' '))
+        .take(column.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    format!("{gutter} |\n{line} | {text}\n{gutter} | {padding}^")
 }
 
 macro_rules! impl_into_aoc_error {
     ($ty: ty) => {
         impl From<$ty> for AoCError {
             fn from(error: $ty) -> Self {
-                error.into()
+                AoCError::ParseError(error.into())
             }
         }
     };
@@ -51,3 +99,12 @@ macro_rules! impl_into_aoc_error {
 
 impl_into_aoc_error!(chrono::format::ParseError);
 impl_into_aoc_error!(std::num::ParseIntError);
+impl_into_aoc_error!(toml::de::Error);
+
+/// Lets parsers built on infallible conversions, such as `char` into `char`, share
+/// code with fallible ones
+impl From<std::convert::Infallible> for AoCError {
+    fn from(error: std::convert::Infallible) -> Self {
+        match error {}
+    }
+}
@@ -0,0 +1,423 @@
+//! Points and directions on the integer plane and in space
+//!
+//! Directions follow the same axes as [`Grid`](crate::grid::Grid), with `x`
+//! increasing to the east and `y` increasing to the south, so north is up the page.
+//! # Example
+//! ```rust
+//! use advent_of_code::geom::{Direction, Point2, Turn};
+//!
+//! let start = Point2::new(0, 0);
+//! let facing = Direction::North.turn(Turn::Right);
+//! let end = start + facing * 3 + Direction::South;
+//!
+//! assert_eq!(end, Point2::new(3, 1));
+//! assert_eq!(start.manhattan(end), 4);
+//! assert_eq!(start.chebyshev(end), 3);
+//! ```
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use anyhow::anyhow;
+
+use crate::error::{AoCError, Result};
+
+/// Point on the integer plane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    /// Distance moving only north, south, east or west
+    pub fn manhattan(self, other: Point2) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance moving diagonally too, as a king does in chess
+    pub fn chebyshev(self, other: Point2) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Point one step away in a direction
+    pub fn step(self, direction: impl Into<Point2>) -> Point2 {
+        self + direction.into()
+    }
+
+    /// Points north, east, south and west of this one
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// Points around this one, diagonals included, clockwise from north
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Direction8::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// Position in a [`Grid`](crate::grid::Grid), if neither coordinate is negative
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+/// Point at a position in a [`Grid`](crate::grid::Grid)
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+/// Point in space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Distance moving along one axis at a time
+    pub fn manhattan(self, other: Point3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Distance moving along any number of axes at once
+    pub fn chebyshev(self, other: Point3) -> u64 {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    /// Points one step away along each axis
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+/// Implements the arithmetic operators for a point type, coordinate by coordinate
+macro_rules! impl_point_ops {
+    ($point: ident { $($axis: ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, scale: i64) -> $point {
+                $point { $($axis: self.$axis * scale),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+/// Way to turn, or to choose between two things, given as `L` or `R` by many puzzles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = AoCError;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(anyhow!("expected L or R, found {c:?}").into()),
+        }
+    }
+}
+
+/// One of the four points of the compass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Offset of a single step in this direction
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::North => Point2::new(0, -1),
+            Direction::East => Point2::new(1, 0),
+            Direction::South => Point2::new(0, 1),
+            Direction::West => Point2::new(-1, 0),
+        }
+    }
+
+    /// Direction a quarter turn to the left or right
+    pub fn turn(self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    /// Direction a quarter turn anticlockwise
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Direction a quarter turn clockwise
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Direction facing the other way
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the eight points of the compass, diagonals included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Offset of a single step in this direction
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction8::North => Point2::new(0, -1),
+            Direction8::NorthEast => Point2::new(1, -1),
+            Direction8::East => Point2::new(1, 0),
+            Direction8::SouthEast => Point2::new(1, 1),
+            Direction8::South => Point2::new(0, 1),
+            Direction8::SouthWest => Point2::new(-1, 1),
+            Direction8::West => Point2::new(-1, 0),
+            Direction8::NorthWest => Point2::new(-1, -1),
+        }
+    }
+
+    /// Direction an eighth of a turn to the left or right
+    pub fn turn(self, turn: Turn) -> Direction8 {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    /// Direction an eighth of a turn anticlockwise
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Direction an eighth of a turn clockwise
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Direction facing the other way
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+impl From<Direction> for Point2 {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl From<Direction8> for Point2 {
+    fn from(direction: Direction8) -> Self {
+        direction.offset()
+    }
+}
+
+/// Steps by one of the direction's offsets
+impl Add<Direction> for Point2 {
+    type Output = Point2;
+
+    fn add(self, direction: Direction) -> Point2 {
+        self + direction.offset()
+    }
+}
+
+/// Offset of `n` steps in the direction
+impl Mul<i64> for Direction {
+    type Output = Point2;
+
+    fn mul(self, steps: i64) -> Point2 {
+        self.offset() * steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn measures_distances() {
+        let a = Point2::new(-2, 3);
+        let b = Point2::new(4, -1);
+
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(b - a, Point2::new(6, -4));
+        assert_eq!(-a * 2, Point2::new(4, -6));
+
+        let c = Point3::new(1, -2, 3);
+
+        assert_eq!(Point3::ORIGIN.manhattan(c), 6);
+        assert_eq!(Point3::ORIGIN.chebyshev(c), 3);
+        assert_eq!(c.neighbours6().filter(|n| n.z == 3).count(), 4);
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        assert_eq!(Direction::South.turn(Turn::Left), Direction::East);
+
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+        assert_eq!(Direction8::SouthEast.reverse(), Direction8::NorthWest);
+
+        for direction in Direction::ALL {
+            let offset = direction.offset();
+
+            assert_eq!(Direction8::from(direction).offset(), offset);
+            assert_eq!(direction.reverse().offset(), -offset);
+        }
+
+        assert_eq!(Turn::try_from('R').unwrap(), Turn::Right);
+        assert!(Turn::try_from('X').is_err());
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let neighbours: Vec<_> = Point2::ORIGIN.neighbours4().collect();
+
+        assert_eq!(
+            neighbours,
+            [
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0)
+            ]
+        );
+        assert_eq!(
+            Point2::ORIGIN
+                .neighbours8()
+                .map(|n| Point2::ORIGIN.chebyshev(n))
+                .sum::<u64>(),
+            8
+        );
+    }
+
+    #[test]
+    fn steps_within_grids() {
+        let grid = Grid::new(3, 2, ());
+
+        assert_eq!(grid.step((0, 0), Direction::East), Some((1, 0)));
+        assert_eq!(grid.step((0, 0), Direction::North), None);
+        assert_eq!(grid.step((2, 1), Direction8::SouthEast), None);
+        assert_eq!(grid.step((2, 1), Direction8::NorthWest), Some((1, 0)));
+        assert_eq!(Point2::new(-1, 0).to_position(), None);
+    }
+}
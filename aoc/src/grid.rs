@@ -0,0 +1,455 @@
+//! Rectangular grids of cells, as drawn by many AoC puzzle inputs
+//!
+//! Cells are stored in a single `Vec` a row at a time and addressed by `(x, y)`
+//! positions, with `(0, 0)` the top left cell, `x` increasing to the right and `y`
+//! increasing downwards.
+//! # Example
+//! ```rust
+//! use advent_of_code::grid::Grid;
+//!
+//! let grid: Grid<char> = "ab\ncd".parse().unwrap();
+//!
+//! assert_eq!(grid.get((1, 0)), Some(&'b'));
+//! assert_eq!(grid.get((2, 0)), None);
+//! assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+//! assert_eq!(grid.transpose().to_string(), "ac\nbd");
+//! ```
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::anyhow;
+
+use crate::{
+    error::{AoCError, Result},
+    geom::Point2,
+};
+
+/// Offsets to the cells above, right, below and left of a cell
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to the cells around a cell, in reading order
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Grid of cells with a fixed width and height
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid from its rows, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(
+                    anyhow!("row {y} of grid has {} cells, expected {width}", row.len()).into(),
+                );
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a character map a line at a time, turning each character into a cell
+    /// with `cell`. Blank lines around the map and whitespace around each line are
+    /// ignored.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let rows = input
+            .trim()
+            .lines()
+            .map(|line| line.trim().chars().map(&mut cell).collect())
+            .collect::<Result<_>>()?;
+
+        let grid = Grid::from_rows(rows)?;
+
+        if grid.cells.is_empty() {
+            return Err(anyhow!("expected a grid of at least one cell").into());
+        }
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether a position lies within the grid
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    fn offset(&self, position: (usize, usize)) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.1 * self.width + position.0)
+    }
+
+    /// Cell at a position, or `None` if it's outside the grid
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    /// Mutable cell at a position, or `None` if it's outside the grid
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.offset(position).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every position in the grid, in reading order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, in reading order
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, in reading order, matching `predicate`
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    fn neighbours<'a>(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<'a, T> {
+        let (width, height) = (self.width, self.height);
+
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+            let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+
+            Some((x, y))
+        })
+    }
+
+    /// Position a step away in a direction, such as a
+    /// [`Direction`](crate::geom::Direction), or `None` if that's outside the grid
+    pub fn step(
+        &self,
+        position: (usize, usize),
+        direction: impl Into<Point2>,
+    ) -> Option<(usize, usize)> {
+        let next = Point2::from(position).step(direction).to_position()?;
+
+        self.contains(next).then_some(next)
+    }
+
+    /// Positions above, right, below and left of a position that lie within the grid
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(position, &NEIGHBOURS4)
+    }
+
+    /// Positions around a position, diagonals included, that lie within the grid
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(position, &NEIGHBOURS8)
+    }
+
+    /// Cells of row `y`, left to right
+    ///
+    /// # Panics
+    /// If the row is outside the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Cells of column `x`, top to bottom
+    ///
+    /// # Panics
+    /// If the column is outside the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Rows, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero size, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Columns, left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells along a line from `start`, moving by `(dx, dy)` until leaving the grid
+    fn line(&self, start: (usize, usize), (dx, dy): (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |&(x, y)| {
+            Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+        })
+        .map_while(|position| self.get(position))
+    }
+
+    /// Diagonals running down and to the right, starting from the top right corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .rev()
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(|y| (0, y)));
+
+        starts.map(|start| self.line(start, (1, 1)))
+    }
+
+    /// Diagonals running down and to the left, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(|y| (self.width.saturating_sub(1), y)));
+
+        starts.map(|start| self.line(start, (-1, 1)))
+    }
+
+    /// Grid with each cell replaced by `f` of it
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grid built from the cells at the positions `position` gives for each of its
+    /// own, which has the given size
+    fn rearrange(
+        &self,
+        width: usize,
+        height: usize,
+        position: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[position(x, y)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid flipped over its top left to bottom right diagonal, so rows become columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Grid turned a quarter turn clockwise
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Grid turned a quarter turn anticlockwise
+    pub fn rotate_anticlockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+}
+
+/// # Panics
+/// If the position is outside the grid
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        match self.offset(position) {
+            Some(offset) => &self.cells[offset],
+            None => panic!("position {position:?} is outside the grid"),
+        }
+    }
+}
+
+/// # Panics
+/// If the position is outside the grid
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        match self.offset(position) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!("position {position:?} is outside the grid"),
+        }
+    }
+}
+
+/// Parses a character map with each cell's `TryFrom<char>`
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    AoCError: From<T::Error>,
+{
+    type Err = AoCError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse_with(s, |c| Ok(T::try_from(c)?))
+    }
+}
+
+/// Draws the grid a row per line
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn parses_character_maps() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+
+        let digits = Grid::parse_with("  12\n  34  \n", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| anyhow!("not a digit {c}").into())
+        })
+        .unwrap();
+
+        assert_eq!(digits.row(1), [3, 4]);
+    }
+
+    #[test]
+    fn rejects_invalid_maps() {
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert!("\n\n".parse::<Grid<char>>().is_err());
+        assert!(Grid::parse_with("ab", |_| Err::<(), _>(anyhow!("bad").into())).is_err());
+    }
+
+    #[test]
+    fn finds_neighbours_within_the_grid() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((2, 0)).collect::<Vec<_>>(),
+            [(1, 0), (1, 1), (2, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let grid = grid();
+
+        assert_eq!(collect(grid.rows().map(|row| row.iter())), ["abc", "def"]);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["c", "bf", "ae", "d"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn turns_grids() {
+        let grid = grid();
+
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+    }
+
+    #[test]
+    fn modifies_cells() {
+        let mut grid = Grid::new(2, 2, 0);
+
+        grid[(1, 0)] = 1;
+        *grid.get_mut((0, 1)).unwrap() = 2;
+
+        assert_eq!(grid.get_mut((2, 2)), None);
+        assert_eq!(grid.map(|n| n * 10).to_string(), "010\n200");
+    }
+}
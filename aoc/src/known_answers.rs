@@ -0,0 +1,239 @@
+//! Known answers for the inputs checked into the repository
+//!
+//! Each year keeps an `answers.toml` next to its inputs, with a table per day:
+//! ```toml
+//! [day01]
+//! part_one = 1228
+//! part_two = "ymdrchgpvwfloluktajxijsqb"
+//! ```
+//! # Example
+//! ```rust
+//! use advent_of_code::{known_answers, Answer, Part};
+//! use advent_of_code::known_answers::Verdict;
+//!
+//! let known = known_answers::find(2017, 1).unwrap().expect("2017 day 1 is known");
+//!
+//! assert_eq!(known.check(Part::One, Some(&Answer::from(1228))), Verdict::Pass);
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::{
+    Answer, Part, Solution,
+    error::{AoCError, Result},
+};
+
+const YEARS: &[(u16, &str)] = &[
+    (2017, crate::aoc2017::ANSWERS),
+    (2018, crate::aoc2018::ANSWERS),
+    (2019, crate::aoc2019::ANSWERS),
+    (2020, crate::aoc2020::ANSWERS),
+    (2021, crate::aoc2021::ANSWERS),
+    (2022, crate::aoc2022::ANSWERS),
+    (2023, crate::aoc2023::ANSWERS),
+    (2024, crate::aoc2024::ANSWERS),
+    (2025, crate::aoc2025::ANSWERS),
+];
+
+/// Answers as written in the TOML files
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Int(i64),
+    Str(String),
+}
+
+impl From<RawAnswer> for Answer {
+    fn from(raw: RawAnswer) -> Self {
+        match raw {
+            RawAnswer::Int(n) => Answer::Int(n),
+            RawAnswer::Str(s) => Answer::from(s),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawDay {
+    part_one: Option<RawAnswer>,
+    part_two: Option<RawAnswer>,
+}
+
+/// Known answers to a day, either part may be unknown
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnownAnswers {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+/// Outcome of checking an answer against the known one
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    /// The answer matches
+    Pass,
+    /// The answer doesn't match
+    Mismatch { expected: Answer, actual: Answer },
+    /// There is a known answer but the part wasn't solved
+    Missing { expected: Answer },
+    /// There is no known answer to check against
+    Unknown,
+}
+
+impl KnownAnswers {
+    /// Known answer to a part
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+
+    /// Checks an answer to a part, `None` or [`Answer::NotImplemented`] when it wasn't solved
+    pub fn check(&self, part: Part, actual: Option<&Answer>) -> Verdict {
+        match (self.get(part), actual) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), None | Some(Answer::NotImplemented)) => Verdict::Missing {
+                expected: expected.clone(),
+            },
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), Some(actual)) => Verdict::Mismatch {
+                expected: expected.clone(),
+                actual: actual.clone(),
+            },
+        }
+    }
+
+    /// Checks both parts of a solution
+    pub fn check_solution(&self, solution: &Solution) -> [Verdict; 2] {
+        [
+            self.check(Part::One, solution.part_one.as_ref()),
+            self.check(Part::Two, solution.part_two.as_ref()),
+        ]
+    }
+}
+
+/// Parses the known answers of a year
+pub fn parse(year: u16, toml: &str) -> Result<Vec<KnownAnswers>> {
+    let days: BTreeMap<String, RawDay> = toml::from_str(toml)?;
+
+    days.into_iter()
+        .map(|(key, raw)| {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| {
+                    AoCError::from(anyhow::anyhow!("expected dayNN table, got {key}"))
+                })?;
+
+            Ok(KnownAnswers {
+                year,
+                day,
+                part_one: raw.part_one.map(Answer::from),
+                part_two: raw.part_two.map(Answer::from),
+            })
+        })
+        .collect()
+}
+
+/// Known answers to every day of a year
+pub fn for_year(year: u16) -> Result<Vec<KnownAnswers>> {
+    match YEARS.iter().find(|(known_year, _)| *known_year == year) {
+        Some((year, toml)) => parse(*year, toml),
+        None => Ok(vec![]),
+    }
+}
+
+/// Known answers to a day, if any were recorded
+pub fn find(year: u16, day: u8) -> Result<Option<KnownAnswers>> {
+    Ok(for_year(year)?.into_iter().find(|known| known.day == day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_year_parses_and_has_a_solver() {
+        for (year, toml) in YEARS {
+            for known in parse(*year, toml).unwrap() {
+                assert!(
+                    crate::solver::find(known.year, known.day).is_some(),
+                    "{} day {} has answers but no solver",
+                    known.year,
+                    known.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parses_numbers_and_text() {
+        let known = parse(
+            2018,
+            r#"
+            [day02]
+            part_one = 5000
+            part_two = "ymdrchgpvwfloluktajxijsqb"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(known[0].day, 2);
+        assert_eq!(known[0].part_one, Some(Answer::Int(5000)));
+        assert_eq!(
+            known[0].part_two,
+            Some(Answer::from("ymdrchgpvwfloluktajxijsqb"))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_days() {
+        assert!(parse(2017, "[first]\npart_one = 1").is_err());
+        assert!(parse(2017, "[day01]\npart_three = 1").is_err());
+    }
+
+    #[test]
+    fn checks_solutions() {
+        let known = find(2023, 10).unwrap().unwrap();
+        let solution = Solution {
+            part_one: Some(Answer::from(7031u32)),
+            part_two: Some(Answer::NotImplemented),
+        };
+
+        assert_eq!(
+            known.check_solution(&solution),
+            [
+                Verdict::Mismatch {
+                    expected: Answer::from(7030),
+                    actual: Answer::from(7031)
+                },
+                Verdict::Unknown
+            ]
+        );
+        assert_eq!(
+            known.check(Part::One, None),
+            Verdict::Missing {
+                expected: Answer::from(7030)
+            }
+        );
+    }
+
+    #[test]
+    fn numbers_written_as_text_match_numeric_answers() {
+        let known = find(2018, 3).unwrap().unwrap();
+
+        assert_eq!(known.part_two, Some(Answer::from("116")));
+        assert_eq!(
+            known.check(Part::Two, Some(&Answer::from(116))),
+            Verdict::Pass
+        );
+        assert_eq!(
+            known.check(Part::Two, Some(&Answer::from("116"))),
+            Verdict::Pass
+        );
+    }
+}
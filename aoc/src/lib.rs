@@ -8,8 +8,8 @@
 //!
 //! match solve_day(&config) {
 //!     Ok(solution) => {
-//!         assert_eq!(solution.part_one, "9");
-//!         assert_eq!(solution.part_two, "6");
+//!         assert_eq!(solution.part_one, Some(9.into()));
+//!         assert_eq!(solution.part_two, Some(6.into()));
 //!     },
 //!     Err(error) => {
 //!         //...
@@ -18,6 +18,7 @@
 //!
 //! ```
 
+pub mod answer;
 pub mod aoc2017;
 pub mod aoc2018;
 pub mod aoc2019;
@@ -28,6 +29,17 @@ pub mod aoc2023;
 pub mod aoc2024;
 pub mod aoc2025;
 pub mod error;
+pub mod geom;
+pub mod grid;
+pub mod known_answers;
+pub mod parse;
+pub mod search;
+pub mod solver;
+
+use std::fmt;
+
+pub use answer::Answer;
+pub use solver::Solver;
 
 /// AoC config
 #[derive(Debug)]
@@ -35,15 +47,42 @@ pub struct Config {
     pub year: u16,
     pub day: u8,
     pub input: String,
+    /// Only solve this part, or both when `None`
+    pub part: Option<Part>,
+    /// Fail on input lines that solvers would otherwise skip over. This only
+    /// reaches parsing done on the thread calling [`solve_day`].
+    pub strict: bool,
+}
+
+/// One of the two parts of a day in AoC
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
 }
 
 /// Solution for a day in AoC
 #[derive(Debug, Clone)]
 pub struct Solution {
-    /// Answer to part one of challenge
-    pub part_one: String,
-    /// Answer to part two of challenge
-    pub part_two: String,
+    /// Answer to part one of challenge, unless only part two was requested
+    pub part_one: Option<Answer>,
+    /// Answer to part two of challenge, unless only part one was requested
+    pub part_two: Option<Answer>,
 }
 
 impl Config {
@@ -55,42 +94,95 @@ impl Config {
     /// let config = Config::new(2017, 1, String::from("6497139596"));
     /// ```
     pub fn new(year: u16, day: u8, input: String) -> Self {
-        Config { year, day, input }
+        Config {
+            year,
+            day,
+            input,
+            part: None,
+            strict: false,
+        }
+    }
+
+    /// Restricts the config to solving a single part
+    /// # Example
+    /// ```
+    /// use advent_of_code::{Config, Part};
+    ///
+    /// let config = Config::new(2017, 1, String::from("6497139596")).with_part(Part::Two);
+    /// ```
+    pub fn with_part(mut self, part: Part) -> Self {
+        self.part = Some(part);
+        self
+    }
+
+    /// Makes solving fail on input that doesn't make sense, rather than skipping it
+    /// # Example
+    /// ```
+    /// use advent_of_code::{solve_day, Config};
+    ///
+    /// let config = Config::new(2017, 1, String::from("1122x")).with_strict();
+    ///
+    /// assert!(solve_day(&config).is_err());
+    /// ```
+    pub fn with_strict(mut self) -> Self {
+        self.strict = true;
+        self
     }
 }
 
 /// Computes the solution to a day in Advent of Code 2017/2018/2019
 /// # Example
 /// ```rust
-/// use advent_of_code::{solve_day, Config};
+/// use advent_of_code::{solve_day, Config, Part};
 ///
 /// let mut config = Config::new(2017, 1, String::from("91212129"));
 ///
 /// match solve_day(&config) {
 ///     Ok(solution) => {
-///         assert_eq!(solution.part_one, "9");
-///         assert_eq!(solution.part_two, "6");
+///         assert_eq!(solution.part_one, Some(9.into()));
+///         assert_eq!(solution.part_two, Some(6.into()));
 ///     },
 ///     Err(error) => {
 ///         //...
 ///     }
 /// };
 ///
+/// config.part = Some(Part::One);
+///
+/// let solution = solve_day(&config).unwrap();
+///
+/// assert_eq!(solution.part_one, Some(9.into()));
+/// assert_eq!(solution.part_two, None);
 /// ```
 pub fn solve_day(config: &Config) -> error::Result<Solution> {
-    match config.year {
-        2017 => aoc2017::run(config),
-        2018 => aoc2018::run(config),
-        2019 => aoc2019::run(config),
-        2020 => aoc2020::run(config),
-        2021 => aoc2021::run(config),
-        2022 => aoc2022::run(config),
-        2023 => aoc2023::run(config),
-        2024 => aoc2024::run(config),
-        2025 => aoc2025::run(config),
-        _ => Err(error::AoCError::UnsupportedDay {
-            day: config.day,
-            year: config.year,
-        }),
-    }
+    let solver = solver::find(config.year, config.day).ok_or(error::AoCError::UnsupportedDay {
+        day: config.day,
+        year: config.year,
+    })?;
+
+    let solve =
+        |part| parse::with_strictness(config.strict, || solver.solve_part(part, &config.input));
+
+    // when solving a whole day an unfinished part shouldn't hide the other one
+    let solve_or_skip = |part| match solve(part) {
+        Err(error::AoCError::NotImplemented { .. }) => Ok(Answer::NotImplemented),
+        result => result,
+    };
+
+    let solution = match config.part {
+        Some(Part::One) => Solution {
+            part_one: Some(solve(Part::One)?),
+            part_two: None,
+        },
+        Some(Part::Two) => Solution {
+            part_one: None,
+            part_two: Some(solve(Part::Two)?),
+        },
+        None => Solution {
+            part_one: Some(solve_or_skip(Part::One)?),
+            part_two: Some(solve_or_skip(Part::Two)?),
+        },
+    };
+
+    Ok(solution)
 }
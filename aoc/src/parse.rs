@@ -0,0 +1,378 @@
+//! Parsers for the shapes puzzle inputs come in, built with [`nom`]
+//!
+//! The combinators here parse pieces of input and can be mixed freely with nom's
+//! own. [`parse_all`] and [`parse_lines`] run a parser over a whole input, turning
+//! failures into a [`ParsingError::Syntax`] that says where the input went wrong
+//! and shows the line it went wrong on.
+//! # Example
+//! ```rust
+//! use advent_of_code::parse::{key_value, numbers, parse_lines};
+//!
+//! let input = "Time: 7 15 30\nDistance: 9 40 -200";
+//! let records = parse_lines(input, key_value(numbers::<i32>)).unwrap();
+//!
+//! assert_eq!(records[1], ("Distance", vec![9, 40, -200]));
+//!
+//! let error = parse_lines("Time: 7\nDistance: x", key_value(numbers::<i32>)).unwrap_err();
+//!
+//! assert_eq!(
+//!     error.to_string(),
+//!     "line 2, column 11: expected a number, found \"x\"\n  |\n2 | Distance: x\n  |           ^"
+//! );
+//! ```
+
+use std::{cell::Cell, fmt::Display, str::FromStr};
+
+use nom::{
+    Offset, Parser,
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{
+        alphanumeric1, char, digit1, line_ending, multispace0, one_of, space0, space1,
+    },
+    combinator::{eof, map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::{many1_count, separated_list1},
+    sequence::{delimited, separated_pair, terminated},
+};
+
+use crate::{error::ParsingError, grid::Grid};
+
+thread_local! {
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether parsers should fail on input they would otherwise skip over
+///
+/// The setting belongs to the current thread, so input has to be parsed on the
+/// thread solving the day. Work handed to other threads, such as with rayon, starts
+/// out lenient; pass `is_strict()` along and re-apply it with [`with_strictness`] if
+/// that work skips input.
+pub fn is_strict() -> bool {
+    STRICT.get()
+}
+
+/// Runs `f` with strict parsing turned on or off for this thread, as
+/// [`solve_day`](crate::solve_day) does for a [`Config`](crate::Config) asking for it
+pub fn with_strictness<T>(strict: bool, f: impl FnOnce() -> T) -> T {
+    /// Puts back the previous setting, even if `f` panics
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            STRICT.set(self.0);
+        }
+    }
+
+    let _restore = Restore(STRICT.replace(strict));
+
+    f()
+}
+
+/// Reports input at `at`, part of `input`, that a parser is skipping over because
+/// it doesn't make sense of it. This fails with `message` in strict mode.
+pub fn skip(input: &str, at: &str, message: impl Display) -> Result<(), ParsingError> {
+    match is_strict() {
+        true => Err(located(input, at, message)),
+        false => Ok(()),
+    }
+}
+
+/// Parses each non-blank line of `input` with `parse`, skipping the lines it fails
+/// on, or failing on the first of them in strict mode
+pub fn lenient_lines<'a, T, E: Display>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, E>,
+) -> Result<Vec<T>, ParsingError> {
+    let mut parsed = vec![];
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match parse(line) {
+            Ok(value) => parsed.push(value),
+            Err(error) => skip(input, line, error)?,
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Result of parsing part of some input, with the input left over
+pub type IResult<'a, T> = nom::IResult<&'a str, T>;
+
+/// Integer with an optional sign, such as `42`, `-7` or `+3`
+pub fn integer<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(recognize((opt(one_of("+-")), digit1)), str::parse).parse(input)
+}
+
+/// Integers on a line separated by commas, spaces or both, such as `1, -2 3`
+pub fn numbers<T: FromStr>(input: &str) -> IResult<'_, Vec<T>> {
+    let separator = alt((delimited(space0, tag(","), space0), space1));
+
+    separated_list1(separator, integer).parse(input)
+}
+
+/// The end of a line followed by one or more blank lines, which separate sections
+/// of input
+pub fn blank_lines(input: &str) -> IResult<'_, &str> {
+    recognize((line_ending, many1_count((space0, line_ending)))).parse(input)
+}
+
+/// Sections separated by blank lines, each parsed by `section`
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<&'a str>> {
+    separated_list1(blank_lines, section)
+}
+
+/// `key: value` line, such as `Game 3: 1 red, 2 blue`, with the value parsed by
+/// `value`
+pub fn key_value<'a, O>(
+    value: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> impl Parser<&'a str, Output = (&'a str, O), Error = Error<&'a str>> {
+    separated_pair(
+        take_till1(|c| c == ':' || c == '\n'),
+        (char(':'), space0),
+        value,
+    )
+}
+
+/// Lines of characters drawing a grid, up to a blank line or the end of input
+pub fn char_grid(input: &str) -> IResult<'_, Grid<char>> {
+    let row = take_till1(|c| c == '\r' || c == '\n');
+    let rows = separated_list1(line_ending, row);
+
+    map_res(rows, |rows: Vec<&str>| {
+        Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect())
+    })
+    .parse(input)
+}
+
+/// `label = (left, right)` node of a network where each node leads to two others
+pub fn node(input: &str) -> IResult<'_, (&str, (&str, &str))> {
+    let neighbours = separated_pair(alphanumeric1, (char(','), space0), alphanumeric1);
+    let equals = (space0, char('='), space0);
+
+    separated_pair(
+        alphanumeric1,
+        equals,
+        delimited(char('('), neighbours, char(')')),
+    )
+    .parse(input)
+}
+
+/// What a parser that failed with `kind` was expecting
+fn expected(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit | ErrorKind::MapRes => "a number".to_string(),
+        ErrorKind::Alpha | ErrorKind::AlphaNumeric => "a word".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "a space".to_string(),
+        ErrorKind::CrLf => "the end of the line".to_string(),
+        ErrorKind::Eof => "nothing more".to_string(),
+        kind => format!("something else ({})", kind.description().to_lowercase()),
+    }
+}
+
+/// Error for input starting at `at`, which is part of `origin`
+fn located(origin: &str, at: &str, message: impl Display) -> ParsingError {
+    let offset = origin.offset(at);
+    let start = origin[..offset]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let text = origin[start..].lines().next().unwrap_or_default();
+
+    ParsingError::Syntax {
+        line: origin[..offset].matches('\n').count() + 1,
+        column: origin[start..offset].chars().count() + 1,
+        message: message.to_string(),
+        text: text.trim_end().to_string(),
+    }
+}
+
+/// Error for a parser that failed on `error.input`, which is part of `origin`
+fn syntax_error(origin: &str, error: Error<&str>) -> ParsingError {
+    let found = match error.input.split_whitespace().next() {
+        Some(word) if !error.input.starts_with(['\r', '\n']) => format!("{word:?}"),
+        _ if error.input.trim().is_empty() => "the end of the input".to_string(),
+        _ => "the end of the line".to_string(),
+    };
+
+    let message = format!("expected {}, found {}", expected(error.code), found);
+
+    located(origin, error.input, message)
+}
+
+/// Runs `parser` over `input`, which must be part of `origin`, failing unless it
+/// parses everything bar trailing whitespace
+fn run<'a, O>(
+    origin: &'a str,
+    input: &'a str,
+    parser: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> Result<O, ParsingError> {
+    match terminated(parser, (multispace0, eof)).parse(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => Err(syntax_error(origin, error)),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers never need more input"),
+    }
+}
+
+/// Parses the whole of `input` with `parser`, ignoring whitespace around it
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> Result<O, ParsingError> {
+    run(input, input.trim_start(), parser)
+}
+
+/// Parses each line of `input` with `parser`, ignoring blank lines and whitespace
+/// around each line
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, Output = O, Error = Error<&'a str>>,
+) -> Result<Vec<O>, ParsingError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| run(input, line, |line| parser.parse(line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(result: Result<impl std::fmt::Debug, ParsingError>) -> (usize, usize, String) {
+        match result {
+            Err(ParsingError::Syntax {
+                line,
+                column,
+                message,
+                ..
+            }) => (line, column, message),
+            result => panic!("expected a syntax error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(integer::<i32>("-12 rest"), Ok((" rest", -12)));
+        assert_eq!(integer::<u8>("+7"), Ok(("", 7)));
+        assert!(integer::<u8>("300").is_err());
+        assert!(integer::<i32>("- 1").is_err());
+
+        assert_eq!(
+            numbers::<i64>("1, -2 3,4\n5"),
+            Ok(("\n5", vec![1, -2, 3, 4]))
+        );
+        assert_eq!(parse_all("  1,2,3\n", numbers::<i64>).unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn parses_sections() {
+        let input = "1 2\n\n3\n  \n\n4 5 6\n";
+        let lists = parse_all(input, sections(separated_list1(line_ending, numbers::<u8>)));
+
+        assert_eq!(
+            lists.unwrap(),
+            [vec![vec![1, 2]], vec![vec![3]], vec![vec![4, 5, 6]]]
+        );
+    }
+
+    #[test]
+    fn parses_shapes_of_input() {
+        assert_eq!(
+            key_value(numbers::<u32>).parse("Game 3: 1 2"),
+            Ok(("", ("Game 3", vec![1, 2])))
+        );
+        assert_eq!(
+            parse_lines("AAA = (BBB, CCC)\n  11A = (11B, XXX)\n", node).unwrap(),
+            [("AAA", ("BBB", "CCC")), ("11A", ("11B", "XXX"))]
+        );
+
+        let (rest, grid) = char_grid("#.\n.#\n\nnext").unwrap();
+
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(rest, "\n\nnext");
+        assert!(char_grid("##\n#\n").is_err());
+    }
+
+    #[test]
+    fn locates_errors() {
+        assert_eq!(
+            error(parse_lines("1\n2\n  3 x\n", numbers::<u8>)),
+            (3, 5, r#"expected nothing more, found "x""#.to_string())
+        );
+        assert_eq!(
+            error(parse_lines("AAA = (BBB, CCC)\nDDD = EEE", node)),
+            (
+                2,
+                7,
+                "expected something else (char), found \"EEE\"".to_string()
+            )
+        );
+        assert_eq!(
+            error(parse_all("key:", key_value(integer::<i32>))),
+            (
+                1,
+                5,
+                "expected a number, found the end of the input".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn shows_where_errors_are() {
+        let error = parse_lines("1 2\n\t3 x  \n", numbers::<u8>).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected nothing more, found \"x\"\n  |\n2 | \t3 x\n  | \t  ^"
+        );
+    }
+
+    #[test]
+    fn skips_lines_unless_strict() {
+        let input = "1\nfoo\n3\n";
+        let parse = |line: &str| line.parse::<u8>();
+
+        assert_eq!(lenient_lines(input, parse).unwrap(), [1, 3]);
+        assert!(!is_strict());
+
+        let error = with_strictness(true, || {
+            assert!(is_strict());
+            lenient_lines(input, parse).unwrap_err()
+        });
+
+        assert!(!is_strict());
+        assert!(
+            error
+                .to_string()
+                .starts_with("line 2, column 1: invalid digit")
+        );
+        assert!(with_strictness(true, || skip(input, &input[4..], "not a number")).is_err());
+        assert!(skip(input, &input[4..], "not a number").is_ok());
+    }
+
+    #[test]
+    fn strictness_stays_on_its_thread() {
+        let input = "1\nfoo\n";
+        let parse = |line: &str| line.parse::<u8>();
+
+        let (spawned, passed_on) = with_strictness(true, || {
+            let strict = is_strict();
+
+            std::thread::scope(|scope| {
+                let spawned = scope.spawn(|| lenient_lines(input, parse)).join().unwrap();
+                let passed_on = scope
+                    .spawn(|| with_strictness(strict, || lenient_lines(input, parse)))
+                    .join()
+                    .unwrap();
+
+                (spawned, passed_on)
+            })
+        });
+
+        assert_eq!(spawned.unwrap(), [1]);
+        assert!(passed_on.is_err());
+    }
+}
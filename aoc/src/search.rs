@@ -0,0 +1,439 @@
+//! Searches over graphs given as closures
+//!
+//! Graphs are never built up front. Instead each search takes a closure giving the
+//! neighbours of a node, along with the cost of moving to each for the weighted
+//! searches, so the same functions work for grids, state machines and explicit
+//! adjacency lists alike.
+//! # Example
+//! ```rust
+//! use advent_of_code::search::{bfs, topological_sort};
+//!
+//! // shortest way from 1 to 10 by adding one or doubling
+//! let path = bfs(1, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+//!
+//! assert_eq!(path, [1, 2, 4, 5, 10]);
+//!
+//! let order = topological_sort(['b', 'a'], |&c| match c {
+//!     'a' => vec!['c'],
+//!     'b' => vec!['c', 'd'],
+//!     _ => vec![],
+//! });
+//!
+//! assert_eq!(order, Some(vec!['a', 'b', 'c', 'd']));
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque, hash_map::Entry},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Follows the parents recorded by a search back from `node` to the start
+fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, node: N) -> Vec<N> {
+    let mut path = vec![node];
+
+    while let Some(Some(parent)) = parents.get(path.last().expect("path is never empty")) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+/// Shortest path from `start` to a node for which `is_goal` is true, counting each
+/// move as one step. The path includes both ends.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Number of steps to every node reachable from `start`
+pub fn bfs_distances<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Cheapest path from `start` to a node for which `is_goal` is true, where
+/// `neighbours` gives each node's neighbours with the cost of moving to them. Costs
+/// must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Cheapest path like [`dijkstra`], exploring the nodes `heuristic` estimates to be
+/// closest to a goal first. The heuristic must never overestimate the remaining
+/// cost, or the path found may not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // nodes live in `seen` and the queue refers to them by index, so nodes need not
+    // be ordered themselves
+    let mut seen: Vec<(N, C)> = vec![(start.clone(), C::default())];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+
+    while let Some(Reverse((_, index))) = queue.pop() {
+        let (node, cost) = seen[index].clone();
+
+        if is_goal(&node) {
+            return Some((reconstruct_path(&parents, node), cost));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+
+            let next_index = match indices.entry(next.clone()) {
+                Entry::Occupied(entry) if seen[*entry.get()].1 <= next_cost => continue,
+                Entry::Occupied(entry) => {
+                    seen[*entry.get()].1 = next_cost;
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    seen.push((next.clone(), next_cost));
+                    *entry.insert(seen.len() - 1)
+                }
+            };
+
+            let estimate = next_cost + heuristic(&next);
+            parents.insert(next, Some(node.clone()));
+            queue.push(Reverse((estimate, next_index)));
+        }
+    }
+
+    None
+}
+
+/// Every node reachable from `nodes`, those given first
+fn reachable<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    successors: &mut impl FnMut(&N) -> I,
+) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut found: Vec<N> = vec![];
+    let mut seen = HashSet::new();
+
+    for node in nodes {
+        if seen.insert(node.clone()) {
+            found.push(node);
+        }
+    }
+
+    let mut index = 0;
+
+    while index < found.len() {
+        for next in successors(&found[index]) {
+            if seen.insert(next.clone()) {
+                found.push(next);
+            }
+        }
+
+        index += 1;
+    }
+
+    found
+}
+
+/// Orders `nodes`, and every node reachable from them, so each comes before its
+/// successors. When several nodes could come next the smallest is taken, so the
+/// order is unique. `None` if the graph has a cycle, and so has no such order.
+pub fn topological_sort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Clone + Ord + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let nodes = reachable(nodes, &mut successors);
+    let mut incoming: HashMap<N, usize> = nodes.iter().map(|node| (node.clone(), 0)).collect();
+
+    for node in &nodes {
+        for next in successors(node) {
+            *incoming.get_mut(&next).expect("successors are reachable") += 1;
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<N>> = nodes
+        .iter()
+        .filter(|node| incoming[*node] == 0)
+        .map(|node| Reverse(node.clone()))
+        .collect();
+    let mut order = Vec::with_capacity(nodes.len());
+
+    while let Some(Reverse(node)) = ready.pop() {
+        for next in successors(&node) {
+            let count = incoming.get_mut(&next).expect("successors are reachable");
+            *count -= 1;
+
+            if *count == 0 {
+                ready.push(Reverse(next));
+            }
+        }
+
+        order.push(node);
+    }
+
+    // nodes on a cycle never run out of incoming edges, so are never ready
+    (order.len() == nodes.len()).then_some(order)
+}
+
+/// A cycle among `nodes` and the nodes reachable from them, starting and ending
+/// with the same node, or `None` if there isn't one
+pub fn find_cycle<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // nodes being visited are in `path`, with `on_path` their index in it, and each
+    // has a list of successors still to visit in `pending`
+    let mut finished = HashSet::new();
+    let mut on_path: HashMap<N, usize> = HashMap::new();
+
+    for start in nodes {
+        if finished.contains(&start) || on_path.contains_key(&start) {
+            continue;
+        }
+
+        let mut path = vec![start.clone()];
+        let mut pending = vec![successors(&start).into_iter().collect::<Vec<_>>()];
+        on_path.insert(start, 0);
+
+        while let Some(unvisited) = pending.last_mut() {
+            let Some(next) = unvisited.pop() else {
+                let node = path.pop().expect("a node per pending list");
+                on_path.remove(&node);
+                finished.insert(node);
+                pending.pop();
+                continue;
+            };
+
+            if let Some(&index) = on_path.get(&next) {
+                let mut cycle = path.split_off(index);
+                cycle.push(next);
+                return Some(cycle);
+            }
+
+            if !finished.contains(&next) {
+                pending.push(successors(&next).into_iter().collect());
+                on_path.insert(next.clone(), path.len());
+                path.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Groups `nodes`, and every node reachable from them, into sets connected to each
+/// other. `neighbours` should be symmetric, as for an undirected graph.
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+
+        let component = reachable([node], &mut neighbours);
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    /// Maze with walls drawn as `#`
+    fn maze() -> Grid<char> {
+        "\
+S.#....
+.##.##.
+...#...
+.#...#E"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn finds_shortest_paths_in_mazes() {
+        let maze = maze();
+        let open = |position: &(usize, usize)| {
+            maze.neighbours4(*position)
+                .filter(|&next| maze[next] != '#')
+                .collect::<Vec<_>>()
+        };
+
+        let path = bfs((0, 0), open, |&position| maze[position] == 'E').unwrap();
+
+        assert_eq!(path.len(), 12);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(6, 3)));
+        assert!(bfs((0, 0), open, |_| false).is_none());
+
+        let distances = bfs_distances((0, 0), open);
+
+        assert_eq!(distances[&(6, 3)], 11);
+        assert_eq!(distances.get(&(2, 0)), None);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // going the long way round avoids the expensive edge
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 10)]),
+            ('b', vec![('d', 2)]),
+            ('d', vec![('c', 3)]),
+            ('c', vec![('e', 1)]),
+        ]);
+        let neighbours = |node: &char| edges.get(node).cloned().unwrap_or_default();
+
+        assert_eq!(
+            dijkstra('a', neighbours, |&node| node == 'e'),
+            Some((vec!['a', 'b', 'd', 'c', 'e'], 7))
+        );
+        assert_eq!(dijkstra('e', neighbours, |&node| node == 'a'), None);
+
+        let maze = maze();
+        let goal: (usize, usize) = (6, 3);
+        let moves = |position: &(usize, usize)| {
+            maze.neighbours4(*position)
+                .filter(|&next| maze[next] != '#')
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let distance = |&(x, y): &(usize, usize)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+
+        let (path, cost) = astar((0, 0), moves, distance, |&position| position == goal).unwrap();
+
+        assert_eq!(cost, 11);
+        assert_eq!(path.len(), 12);
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        // the example from day 7 of 2018
+        let edges = HashMap::from([
+            ('C', vec!['A', 'F']),
+            ('A', vec!['B', 'D']),
+            ('B', vec!['E']),
+            ('D', vec!['E']),
+            ('F', vec!['E']),
+        ]);
+        let successors = |node: &char| edges.get(node).cloned().unwrap_or_default();
+
+        let order: String = topological_sort(['C'], successors)
+            .unwrap()
+            .into_iter()
+            .collect();
+
+        assert_eq!(order, "CABDFE");
+        assert_eq!(find_cycle(['C'], successors), None);
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let successors = |&node: &u32| match node {
+            1 => vec![2],
+            2 => vec![3, 5],
+            3 => vec![4],
+            4 => vec![2],
+            _ => vec![],
+        };
+
+        assert_eq!(topological_sort([1], successors), None);
+        assert_eq!(find_cycle([5, 1], successors), Some(vec![2, 3, 4, 2]));
+        assert_eq!(find_cycle([1], |&n| vec![n]), Some(vec![1, 1]));
+    }
+
+    #[test]
+    fn groups_connected_nodes() {
+        // regions of the same character
+        let maze = maze();
+        let neighbours = |&position: &(usize, usize)| {
+            maze.neighbours4(position)
+                .filter(|&next| maze[next] == maze[position])
+                .collect::<Vec<_>>()
+        };
+
+        let components = connected_components(maze.positions(), neighbours);
+        let sizes: Vec<usize> = components.iter().map(Vec::len).collect();
+
+        assert_eq!(sizes, [1, 1, 3, 17, 2, 1, 1, 1, 1]);
+        assert_eq!(components[1], [(1, 0)]);
+    }
+}
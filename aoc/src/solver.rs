@@ -0,0 +1,276 @@
+//! Solver registry
+//!
+//! Every day declares a [`Puzzle`] describing itself, each year module lists
+//! the days it has in `SOLVERS`, and [`solvers`] stitches the years together.
+//! # Example
+//! ```rust
+//! use advent_of_code::solver;
+//!
+//! let solver = solver::find(2017, 1).expect("2017 day 1 is solved");
+//!
+//! assert_eq!(solver.title(), "Inverse Captcha");
+//! assert_eq!(solver.part_one("91212129").unwrap(), "9");
+//! ```
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{
+    Answer, Part,
+    error::{AoCError, Result},
+};
+
+/// A solution to a single day of AoC
+pub trait Solver: Sync {
+    /// Year the puzzle belongs to
+    fn year(&self) -> u16;
+    /// Day of the puzzle
+    fn day(&self) -> u8;
+    /// Title of the puzzle as shown on the AoC website
+    fn title(&self) -> &'static str;
+    /// Computes the answer to part one of the puzzle
+    fn part_one(&self, input: &str) -> Result<Answer>;
+    /// Computes the answer to part two of the puzzle
+    fn part_two(&self, input: &str) -> Result<Answer>;
+    /// Parses the input without solving anything, for days that parse it the same
+    /// way for both parts. `None` when the day has no separate parsing step.
+    fn parse(&self, _input: &str) -> Option<Result<()>> {
+        None
+    }
+
+    /// Computes the answer to the given part of the puzzle
+    ///
+    /// Unlike calling the parts directly, an unfinished part is reported as
+    /// [`AoCError::NotImplemented`] and a panicking solver as [`AoCError::Panic`].
+    fn solve_part(&self, part: Part, input: &str) -> Result<Answer> {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }));
+
+        match result {
+            Ok(Ok(Answer::NotImplemented)) => Err(AoCError::NotImplemented {
+                year: self.year(),
+                day: self.day(),
+                part,
+            }),
+            Ok(Err(AoCError::ParseError(error))) => Err(AoCError::InvalidInput {
+                year: self.year(),
+                day: self.day(),
+                error,
+            }),
+            Ok(result) => result,
+            Err(payload) => Err(AoCError::Panic {
+                year: self.year(),
+                day: self.day(),
+                part,
+                message: panic_message(payload.as_ref()),
+            }),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// A [`Solver`] backed by a day's `part_one` and `part_two` functions
+pub struct Puzzle {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    part_one: fn(&str) -> Result<Answer>,
+    part_two: fn(&str) -> Result<Answer>,
+    parse: Option<fn(&str) -> Result<()>>,
+}
+
+impl Puzzle {
+    /// Describes a day of AoC
+    pub const fn new(
+        year: u16,
+        day: u8,
+        title: &'static str,
+        part_one: fn(&str) -> Result<Answer>,
+        part_two: fn(&str) -> Result<Answer>,
+    ) -> Self {
+        Puzzle {
+            year,
+            day,
+            title,
+            part_one,
+            part_two,
+            parse: None,
+        }
+    }
+
+    /// Adds the day's parsing step, so it can be timed on its own
+    pub const fn with_parse(mut self, parse: fn(&str) -> Result<()>) -> Self {
+        self.parse = Some(parse);
+        self
+    }
+}
+
+impl Solver for Puzzle {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn title(&self) -> &'static str {
+        self.title
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer> {
+        (self.part_one)(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer> {
+        (self.part_two)(input)
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        self.parse.map(|parse| parse(input))
+    }
+}
+
+const YEARS: &[&[&dyn Solver]] = &[
+    crate::aoc2017::SOLVERS,
+    crate::aoc2018::SOLVERS,
+    crate::aoc2019::SOLVERS,
+    crate::aoc2020::SOLVERS,
+    crate::aoc2021::SOLVERS,
+    crate::aoc2022::SOLVERS,
+    crate::aoc2023::SOLVERS,
+    crate::aoc2024::SOLVERS,
+    crate::aoc2025::SOLVERS,
+];
+
+/// Iterates over every available solution, ordered by year and then day
+pub fn solvers() -> impl Iterator<Item = &'static dyn Solver> {
+    YEARS.iter().flat_map(|year| year.iter().copied())
+}
+
+/// Iterates over the solutions available for a year
+pub fn solvers_for_year(year: u16) -> impl Iterator<Item = &'static dyn Solver> {
+    solvers().filter(move |solver| solver.year() == year)
+}
+
+/// Years with at least one solution
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS
+        .iter()
+        .filter_map(|year| year.first().map(|solver| solver.year()))
+}
+
+/// Looks up the solution to a day
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Solver> {
+    solvers().find(|solver| solver.year() == year && solver.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_ordered_and_unique() {
+        let keys: Vec<(u16, u8)> = solvers()
+            .map(|solver| (solver.year(), solver.day()))
+            .collect();
+
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn years_match_their_solutions() {
+        for year in years() {
+            assert!(solvers_for_year(year).all(|solver| solver.year() == year));
+        }
+
+        assert_eq!(years().next(), Some(2017));
+    }
+
+    #[test]
+    fn find_known_and_unknown_days() {
+        let solver = find(2023, 9).unwrap();
+
+        assert_eq!(solver.title(), "Mirage Maintenance");
+        assert!(find(2016, 1).is_none());
+        assert!(find(2017, 25).is_none());
+    }
+
+    #[test]
+    fn solve_part_reports_unfinished_parts() {
+        let solver = find(2025, 2).unwrap();
+
+        assert!(matches!(
+            solver.solve_part(Part::Two, ""),
+            Err(AoCError::NotImplemented {
+                year: 2025,
+                day: 2,
+                part: Part::Two
+            })
+        ));
+    }
+
+    #[test]
+    fn solve_part_catches_panics() {
+        let puzzle = Puzzle::new(
+            2015,
+            1,
+            "Not Quite Lisp",
+            |_| panic!("floor {} does not exist", -1),
+            |_| Ok(Answer::from(0)),
+        );
+
+        match puzzle.solve_part(Part::One, "") {
+            Err(AoCError::Panic { message, part, .. }) => {
+                assert_eq!(message, "floor -1 does not exist");
+                assert_eq!(part, Part::One);
+            }
+            result => panic!("expected a panic error, got {result:?}"),
+        }
+
+        assert_eq!(puzzle.solve_part(Part::Two, "").unwrap(), 0);
+    }
+
+    #[test]
+    fn solve_part_says_which_day_has_invalid_input() {
+        let solver = find(2023, 2).unwrap();
+
+        match solver.solve_part(Part::One, "Game 1: 3 blue\nGame 2 4 red") {
+            Err(error @ AoCError::InvalidInput { .. }) => {
+                assert!(
+                    error
+                        .to_string()
+                        .starts_with("invalid input for day 2 of 2023: line 2, column 7:")
+                );
+            }
+            result => panic!("expected an invalid input error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_on_its_own_when_the_day_can() {
+        let solver = find(2017, 1).unwrap();
+
+        assert!(matches!(solver.parse("1122"), Some(Ok(()))));
+        assert!(matches!(solver.parse(""), Some(Err(_))));
+        assert!(find(2025, 2).unwrap().parse("").is_none());
+    }
+
+    #[test]
+    fn solve_part_dispatches_to_the_right_part() {
+        let solver = find(2017, 1).unwrap();
+
+        assert_eq!(solver.solve_part(Part::One, "1122").unwrap(), "3");
+        assert_eq!(solver.solve_part(Part::Two, "1212").unwrap(), "6");
+    }
+}
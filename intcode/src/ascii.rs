@@ -0,0 +1,101 @@
+//! Talk to intcode programs that speak ASCII
+//!
+//! Text programs read lines a character at a time and output text the same way,
+//! usually ending with a value too large to be a character, the puzzle's answer.
+
+use crate::intcode::{IntCodeExecutor, Value};
+
+/// Output of a text program
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Text {
+    /// The values that were ASCII characters
+    pub text: String,
+    /// The values that weren't, in the order they were output
+    pub values: Vec<Value>,
+}
+
+impl Text {
+    /// The last value that wasn't a character, which is usually the answer
+    pub fn answer(&self) -> Option<Value> {
+        self.values.last().copied()
+    }
+}
+
+impl From<&[Value]> for Text {
+    fn from(output: &[Value]) -> Self {
+        let mut text = Text::default();
+
+        for &value in output {
+            match u8::try_from(value) {
+                Ok(byte) if byte.is_ascii() => text.text.push(char::from(byte)),
+                _ => text.values.push(value),
+            }
+        }
+
+        text
+    }
+}
+
+impl IntCodeExecutor {
+    /// Queues a line of text as input, a character at a time followed by a newline
+    pub fn push_line(&mut self, line: &str) {
+        for c in line.chars().chain(['\n']) {
+            self.push_input(c as Value);
+        }
+    }
+
+    /// Takes the output so far as text
+    pub fn take_text(&mut self) -> Text {
+        Text::from(&self.take_output()[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+    use crate::intcode::State;
+
+    #[test]
+    fn separates_text_from_answers() {
+        let text = Text::from(&[72, 105, 10, 1000, -1, 33][..]);
+
+        assert_eq!(text.text, "Hi\n!");
+        assert_eq!(text.values, [1000, -1]);
+        assert_eq!(text.answer(), Some(-1));
+    }
+
+    #[test]
+    fn echoes_lines() {
+        // echoes a line back, then outputs its answer
+        let program = assemble(
+            "
+            loop:   in c
+                    out c
+                    eq c, #10, end
+                    jf end, #loop
+                    out #19690720
+                    hlt
+            c:      data 0
+            end:    data 0
+            ",
+        )
+        .unwrap();
+
+        let mut executor = IntCodeExecutor::new(&program);
+
+        assert_eq!(executor.run().unwrap(), State::NeedsInput);
+
+        executor.push_line("hello");
+
+        assert_eq!(executor.run().unwrap(), State::Halted);
+        assert_eq!(
+            executor.take_text(),
+            Text {
+                text: "hello\n".to_string(),
+                values: vec![19690720]
+            }
+        );
+        assert!(executor.output().is_empty());
+    }
+}
@@ -0,0 +1,295 @@
+//! Assemble intcode programs from a small assembly language
+//!
+//! Each line holds an optional `label:`, then an instruction or a `data` directive,
+//! then an optional `;` comment:
+//!
+//! ```text
+//! start:  in x            ; read a value into x
+//!         mul x, #2, x
+//!         out x
+//!         jt x, #start    ; again, until a zero is read
+//!         hlt
+//! x:      data 0
+//! ```
+//!
+//! The mnemonics are `add`, `mul`, `in`, `out`, `jt`, `jf`, `lt`, `eq`, `arb` and
+//! `hlt`. Operands are numbers or labels, bare for position mode, with a `#` for
+//! immediate mode and an `@` for relative mode. `data` lays out its operands as
+//! they are. A leading address, as printed by the disassembler, is ignored, so a
+//! listing assembles back into the program it came from.
+
+use std::collections::HashMap;
+
+use crate::error::{Error, IntCodeResult};
+use crate::intcode::{Mode, Value};
+
+/// Op code and parameter count of each mnemonic, with the parameter that is written to
+const MNEMONICS: [(&str, Value, usize, Option<usize>); 10] = [
+    ("add", 1, 3, Some(2)),
+    ("mul", 2, 3, Some(2)),
+    ("in", 3, 1, Some(0)),
+    ("out", 4, 1, None),
+    ("jt", 5, 2, None),
+    ("jf", 6, 2, None),
+    ("lt", 7, 3, Some(2)),
+    ("eq", 8, 3, Some(2)),
+    ("arb", 9, 1, None),
+    ("hlt", 99, 0, None),
+];
+
+/// Op code of a mnemonic, without any parameter modes
+pub fn op_code(mnemonic: &str) -> Option<Value> {
+    MNEMONICS
+        .iter()
+        .find(|(name, ..)| *name == mnemonic)
+        .map(|&(_, code, ..)| code)
+}
+
+/// Operand before labels are resolved
+#[derive(Debug)]
+enum Operand<'a> {
+    Number(Value),
+    Label(&'a str),
+}
+
+/// Instruction or data, with the line it came from
+#[derive(Debug)]
+struct Statement<'a> {
+    line: usize,
+    /// Op code, or `None` for data
+    code: Option<Value>,
+    operands: Vec<(Mode, Operand<'a>)>,
+}
+
+fn error(line: usize, message: impl Into<String>) -> Error {
+    Error::AssemblyError {
+        line,
+        message: message.into(),
+    }
+}
+
+fn operand(line: usize, text: &str) -> IntCodeResult<(Mode, Operand<'_>)> {
+    let (mode, text) = match text.split_at_checked(1) {
+        Some(("#", rest)) => (Mode::Immediate, rest.trim_start()),
+        Some(("@", rest)) => (Mode::Relative, rest.trim_start()),
+        _ => (Mode::Position, text),
+    };
+
+    if let Ok(value) = text.parse() {
+        return Ok((mode, Operand::Number(value)));
+    }
+
+    if is_label(text) {
+        return Ok((mode, Operand::Label(text)));
+    }
+
+    Err(error(line, format!("invalid operand {text:?}")))
+}
+
+fn is_label(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn statement(line: usize, text: &str) -> IntCodeResult<Statement<'_>> {
+    let (name, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+
+    let operands = if rest.trim().is_empty() {
+        vec![]
+    } else {
+        rest.split(',')
+            .map(|text| operand(line, text.trim()))
+            .collect::<IntCodeResult<Vec<_>>>()?
+    };
+
+    if name == "data" {
+        if let Some((mode, _)) = operands.iter().find(|(mode, _)| *mode != Mode::Position) {
+            return Err(error(line, format!("data can't be in {mode:?} mode")));
+        }
+
+        return Ok(Statement {
+            line,
+            code: None,
+            operands,
+        });
+    }
+
+    let Some(&(_, code, count, written)) =
+        MNEMONICS.iter().find(|(mnemonic, ..)| *mnemonic == name)
+    else {
+        return Err(error(line, format!("unknown mnemonic {name:?}")));
+    };
+
+    if operands.len() != count {
+        return Err(error(
+            line,
+            format!("{name} takes {count} operands, not {}", operands.len()),
+        ));
+    }
+
+    if let Some(index) = written
+        && operands[index].0 == Mode::Immediate
+    {
+        return Err(error(
+            line,
+            format!("{name} can't write to an immediate operand"),
+        ));
+    }
+
+    let modes = operands.iter().rev().fold(0, |modes, (mode, _)| {
+        let digit = match mode {
+            Mode::Position => 0,
+            Mode::Immediate => 1,
+            Mode::Relative => 2,
+        };
+
+        modes * 10 + digit
+    });
+
+    Ok(Statement {
+        line,
+        code: Some(code + modes * 100),
+        operands,
+    })
+}
+
+/// Assembles a program, ready to run with
+/// [`IntCodeExecutor::new`](crate::intcode::IntCodeExecutor::new)
+pub fn assemble(source: &str) -> IntCodeResult<Vec<Value>> {
+    let mut statements = vec![];
+    let mut labels = HashMap::new();
+    let mut address = 0;
+
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let mut text = text.split(';').next().unwrap_or_default().trim();
+
+        // a listing's addresses
+        if let Some((first, rest)) = text.split_once(char::is_whitespace)
+            && first.parse::<Value>().is_ok()
+        {
+            text = rest.trim_start();
+        }
+
+        while let Some((label, rest)) = text.split_once(':') {
+            let label = label.trim();
+
+            if !is_label(label) {
+                return Err(error(line, format!("invalid label {label:?}")));
+            }
+
+            if labels.insert(label, address).is_some() {
+                return Err(error(line, format!("label {label} is defined twice")));
+            }
+
+            text = rest.trim_start();
+        }
+
+        if text.is_empty() {
+            continue;
+        }
+
+        let statement = statement(line, text)?;
+        address += usize::from(statement.code.is_some()) + statement.operands.len();
+        statements.push(statement);
+    }
+
+    let mut program = Vec::with_capacity(address);
+
+    for statement in statements {
+        program.extend(statement.code);
+
+        for (_, operand) in statement.operands {
+            program.push(match operand {
+                Operand::Number(value) => value,
+                Operand::Label(label) => match labels.get(label) {
+                    Some(&address) => address as Value,
+                    None => Err(error(statement.line, format!("undefined label {label}")))?,
+                },
+            });
+        }
+    }
+
+    Ok(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disasm::listing;
+    use crate::intcode::IntCodeExecutor;
+
+    #[test]
+    fn assembles_original_example() {
+        let program = assemble(
+            "
+            add a, b, 3
+            mul 3, c, 0
+            hlt
+        a:  data 30
+        b:  data 40
+        c:  data 50
+            ",
+        )
+        .unwrap();
+
+        assert_eq!(program, [1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]);
+        assert_eq!(IntCodeExecutor::new(&program).execute().unwrap(), 3500);
+    }
+
+    #[test]
+    fn encodes_parameter_modes() {
+        assert_eq!(
+            assemble("add #4, @-3, @7\nout #1\njt #1, #end\nend: hlt").unwrap(),
+            [22101, 4, -3, 7, 104, 1, 1105, 1, 9, 99]
+        );
+    }
+
+    #[test]
+    fn runs_assembled_loops() {
+        let program = assemble(
+            "
+            ; doubles every input until it reads a zero
+        start:  in x
+                jf x, #end
+                mul x, #2, x
+                out x
+                jt #1, #start
+        end:    hlt
+        x:      data 0
+            ",
+        )
+        .unwrap();
+
+        let mut executor = IntCodeExecutor::new(&program).with_input([3, 5, 0]);
+        executor.run().unwrap();
+
+        assert_eq!(executor.output(), [6, 10]);
+    }
+
+    #[test]
+    fn assembles_disassembled_listings() {
+        let program = [1105, 1, 5, 7, 8, 21101, 4, -3, 7, 204, -1, 99, 42];
+
+        assert_eq!(assemble(&listing(&program)).unwrap(), program);
+    }
+
+    #[test]
+    fn reports_the_line_of_errors() {
+        let errors = [
+            ("hlt\nmov 1, 2", 2),
+            ("add 1, 2", 1),
+            ("hlt\n\nin #3", 3),
+            ("jt #1, #nowhere", 1),
+            ("a: hlt\na: hlt", 2),
+            ("data #1", 1),
+        ];
+
+        for (source, expected) in errors {
+            match assemble(source) {
+                Err(Error::AssemblyError { line, .. }) => assert_eq!(line, expected, "{source}"),
+                result => panic!("{source:?} assembled to {result:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Step through an intcode program, stopping at breakpoints and watched memory
+//!
+//! The [`Debugger`] wraps an executor, running it an instruction at a time and
+//! keeping a trace of the most recent instructions it executed.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::error::IntCodeResult;
+use crate::intcode::{Cursor, Instruction, IntCodeExecutor, State, Value};
+
+/// Most instructions kept in the trace, older ones are dropped
+const TRACE_LIMIT: usize = 100_000;
+
+/// Where execution stops
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// Before executing the instruction at an address
+    Address(Cursor),
+    /// Before executing any instruction with this op code, ignoring parameter modes
+    OpCode(Value),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Address(address) => write!(f, "address {address}"),
+            Breakpoint::OpCode(code) => write!(f, "op code {code}"),
+        }
+    }
+}
+
+/// Why the debugger stopped running the program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// A single step finished
+    Stepped,
+    /// The next instruction is at a breakpoint
+    Breakpoint(Breakpoint),
+    /// The last instruction changed a watched memory cell
+    Watchpoint {
+        address: Cursor,
+        old: Value,
+        new: Value,
+    },
+    /// The program is waiting on input
+    NeedsInput,
+    /// The program halted
+    Halted,
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stop::Stepped => write!(f, "stepped"),
+            Stop::Breakpoint(breakpoint) => write!(f, "breakpoint at {breakpoint}"),
+            Stop::Watchpoint { address, old, new } => {
+                write!(f, "address {address} changed from {old} to {new}")
+            }
+            Stop::NeedsInput => write!(f, "waiting on input"),
+            Stop::Halted => write!(f, "halted"),
+        }
+    }
+}
+
+/// Instruction the debugger executed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    /// Number of instructions executed before this one
+    pub step: usize,
+    pub address: Cursor,
+    pub relative_base: Value,
+    pub instruction: Instruction,
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>8}  {:04}  rb {:<6} {}",
+            self.step, self.address, self.relative_base, self.instruction
+        )
+    }
+}
+
+/// Runs an executor an instruction at a time
+#[derive(Debug, Clone, Default)]
+pub struct Debugger {
+    executor: IntCodeExecutor,
+    breakpoints: BTreeSet<Breakpoint>,
+    watchpoints: BTreeSet<Cursor>,
+    trace: VecDeque<Trace>,
+    steps: usize,
+    output: Vec<Value>,
+}
+
+impl Debugger {
+    pub fn new(executor: IntCodeExecutor) -> Self {
+        Debugger {
+            executor,
+            ..Default::default()
+        }
+    }
+
+    pub fn executor(&self) -> &IntCodeExecutor {
+        &self.executor
+    }
+
+    /// The executor, to give it input or edit its memory
+    pub fn executor_mut(&mut self) -> &mut IntCodeExecutor {
+        &mut self.executor
+    }
+
+    /// Number of instructions executed so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn breakpoints(&self) -> impl Iterator<Item = &Breakpoint> {
+        self.breakpoints.iter()
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.insert(breakpoint);
+    }
+
+    /// Removes a breakpoint, returning whether it was set
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.remove(&breakpoint)
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = &Cursor> {
+        self.watchpoints.iter()
+    }
+
+    /// Stops whenever an instruction changes the value at an address
+    pub fn watch(&mut self, address: Cursor) {
+        self.watchpoints.insert(address);
+    }
+
+    /// Stops watching an address, returning whether it was watched
+    pub fn unwatch(&mut self, address: Cursor) -> bool {
+        self.watchpoints.remove(&address)
+    }
+
+    /// Takes the values output so far
+    pub fn take_output(&mut self) -> Vec<Value> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// The most recently executed instructions, oldest first
+    pub fn trace(&self) -> impl Iterator<Item = &Trace> {
+        self.trace.iter()
+    }
+
+    /// Writes the trace, one instruction per line
+    pub fn write_trace(&self, mut writer: impl Write) -> io::Result<()> {
+        for trace in &self.trace {
+            writeln!(writer, "{trace}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Value at an address, memory past the end of the program reads as zero
+    fn value_at(&self, address: Cursor) -> Value {
+        self.executor.memory().get(address).copied().unwrap_or(0)
+    }
+
+    /// Executes the next instruction
+    pub fn step(&mut self) -> IntCodeResult<Stop> {
+        let trace = Trace {
+            step: self.steps,
+            address: self.executor.cursor(),
+            relative_base: self.executor.relative_base(),
+            instruction: self.executor.instruction()?,
+        };
+
+        let watched: Vec<(Cursor, Value)> = self
+            .watchpoints
+            .iter()
+            .map(|&address| (address, self.value_at(address)))
+            .collect();
+
+        match self.executor.step()? {
+            Some(State::NeedsInput) => return Ok(Stop::NeedsInput),
+            Some(State::Halted) => return Ok(Stop::Halted),
+            Some(State::Output(value)) => self.output.push(value),
+            None => {}
+        }
+
+        self.steps += 1;
+
+        if self.trace.len() == TRACE_LIMIT {
+            self.trace.pop_front();
+        }
+        self.trace.push_back(trace);
+
+        let changed = watched
+            .into_iter()
+            .map(|(address, old)| (address, old, self.value_at(address)))
+            .find(|(_, old, new)| old != new);
+
+        if let Some((address, old, new)) = changed {
+            return Ok(Stop::Watchpoint { address, old, new });
+        }
+
+        Ok(Stop::Stepped)
+    }
+
+    /// Breakpoint the next instruction is at, if any
+    fn breakpoint(&self) -> Option<Breakpoint> {
+        let address = Breakpoint::Address(self.executor.cursor());
+        let code = self.executor.memory().get(self.executor.cursor());
+
+        self.breakpoints.iter().copied().find(|breakpoint| {
+            *breakpoint == address
+                || code.is_some_and(|code| *breakpoint == Breakpoint::OpCode(code % 100))
+        })
+    }
+
+    /// Runs until a breakpoint, a watched cell changes, or the program needs input
+    /// or halts. The first instruction runs even if it is at a breakpoint, so
+    /// continuing from a breakpoint moves on.
+    pub fn resume(&mut self) -> IntCodeResult<Stop> {
+        loop {
+            match self.step()? {
+                Stop::Stepped => {}
+                stop => return Ok(stop),
+            }
+
+            if let Some(breakpoint) = self.breakpoint() {
+                return Ok(Stop::Breakpoint(breakpoint));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    fn counter() -> Debugger {
+        let program = assemble(
+            "
+            loop:   add n, #1, n
+                    out n
+                    lt n, #3, done
+                    jt done, #loop
+                    hlt
+            n:      data 0
+            done:   data 0
+            ",
+        )
+        .unwrap();
+
+        Debugger::new(IntCodeExecutor::new(&program))
+    }
+
+    #[test]
+    fn steps_one_instruction_at_a_time() {
+        let mut debugger = counter();
+
+        assert_eq!(debugger.step().unwrap(), Stop::Stepped);
+        assert_eq!(debugger.executor().cursor(), 4);
+        assert_eq!(debugger.step().unwrap(), Stop::Stepped);
+        assert_eq!(debugger.take_output(), [1]);
+        assert_eq!(debugger.steps(), 2);
+    }
+
+    #[test]
+    fn stops_at_breakpoints() {
+        let mut debugger = counter();
+        debugger.add_breakpoint(Breakpoint::Address(6));
+
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Breakpoint(Breakpoint::Address(6))
+        );
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Breakpoint(Breakpoint::Address(6))
+        );
+        assert_eq!(debugger.take_output(), [1, 2]);
+
+        debugger.remove_breakpoint(Breakpoint::Address(6));
+        debugger.add_breakpoint(Breakpoint::OpCode(99));
+
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Breakpoint(Breakpoint::OpCode(99))
+        );
+        assert_eq!(debugger.resume().unwrap(), Stop::Halted);
+        assert_eq!(debugger.take_output(), [3]);
+    }
+
+    #[test]
+    fn stops_when_watched_memory_changes() {
+        let mut debugger = counter();
+        debugger.watch(14);
+
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Watchpoint {
+                address: 14,
+                old: 0,
+                new: 1
+            }
+        );
+        assert_eq!(debugger.executor().cursor(), 4);
+    }
+
+    #[test]
+    fn waits_for_input() {
+        let mut debugger = Debugger::new(IntCodeExecutor::new(&[3, 0, 99]));
+
+        assert_eq!(debugger.resume().unwrap(), Stop::NeedsInput);
+
+        debugger.executor_mut().push_input(5);
+
+        assert_eq!(debugger.resume().unwrap(), Stop::Halted);
+        assert_eq!(debugger.executor().memory()[0], 5);
+    }
+
+    #[test]
+    fn traces_executed_instructions() {
+        let mut debugger = counter();
+        debugger.resume().unwrap();
+
+        let mut trace = vec![];
+        debugger.write_trace(&mut trace).unwrap();
+        let trace = String::from_utf8(trace).unwrap();
+
+        assert_eq!(debugger.trace().count(), 12);
+        assert_eq!(
+            trace.lines().next(),
+            Some("       0  0000  rb 0      add 14, #1, 14")
+        );
+    }
+}
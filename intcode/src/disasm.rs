@@ -0,0 +1,299 @@
+//! Turn intcode programs back into something readable
+//!
+//! Code is found by following the program from address 0, along both sides of every
+//! jump whose target is known, then by looking for blocks of instructions in what is
+//! left. Everything else is listed as data.
+//!
+//! Parameters are written bare in position mode, with a `#` in immediate mode and
+//! an `@` in relative mode, so `add @1, #4, 10` adds 4 to the value one past the
+//! relative base and stores it at address 10. Jump targets get a label, `L` followed
+//! by the address.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::intcode::{Cursor, Instruction, Mode, Param, Value};
+
+/// Most values listed on one line of data
+const DATA_PER_LINE: usize = 8;
+
+/// What is at an address of a program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    Code(Instruction),
+    Data(Vec<Value>),
+}
+
+/// Line of a disassembled program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub address: Cursor,
+    /// Whether something jumps to this address
+    pub label: bool,
+    pub item: Item,
+    /// The values the line was disassembled from
+    pub raw: Vec<Value>,
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Mode::Position => write!(f, "{}", self.value),
+            Mode::Immediate => write!(f, "#{}", self.value),
+            Mode::Relative => write!(f, "@{}", self.value),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+
+        for (index, param) in self.params().iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            write!(f, "{separator}{param}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Name of the label at an address
+pub fn label(address: Cursor) -> String {
+    format!("L{address:04}")
+}
+
+/// Address an instruction always jumps to, when it has an immediate target
+fn jump_target(instruction: &Instruction) -> Option<Cursor> {
+    match instruction {
+        Instruction::JumpIfTrue(_, target) | Instruction::JumpIfFalse(_, target)
+            if target.mode == Mode::Immediate =>
+        {
+            Cursor::try_from(target.value).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Whether execution can carry on to the next instruction
+fn falls_through(instruction: &Instruction) -> bool {
+    match instruction {
+        Instruction::Terminal => false,
+        Instruction::JumpIfTrue(condition, _) if condition.mode == Mode::Immediate => {
+            condition.value == 0
+        }
+        Instruction::JumpIfFalse(condition, _) if condition.mode == Mode::Immediate => {
+            condition.value != 0
+        }
+        _ => true,
+    }
+}
+
+/// Decodes the code reachable from an address, following jumps with known targets
+fn explore(
+    program: &[Value],
+    start: Cursor,
+    code: &mut BTreeMap<Cursor, Instruction>,
+    labels: &mut BTreeSet<Cursor>,
+) {
+    let mut pending = vec![start];
+
+    while let Some(address) = pending.pop() {
+        if address >= program.len() || code.contains_key(&address) {
+            continue;
+        }
+
+        let Ok(instruction) = Instruction::try_from(&program[address..]) else {
+            continue;
+        };
+
+        if let Some(target) = jump_target(&instruction) {
+            labels.insert(target);
+            pending.push(target);
+        }
+
+        if falls_through(&instruction) {
+            pending.push(address + instruction.size());
+        }
+
+        code.insert(address, instruction);
+    }
+}
+
+/// Whether the values from an address decode as instructions, without overlapping code
+/// already found, up to a halt, an unconditional jump or that code
+fn is_block(program: &[Value], mut address: Cursor, code: &BTreeMap<Cursor, Instruction>) -> bool {
+    while address < program.len() && !code.contains_key(&address) {
+        let Ok(instruction) = Instruction::try_from(&program[address..]) else {
+            return false;
+        };
+
+        let end = address + instruction.size();
+
+        if code.range(address + 1..end).next().is_some() {
+            return false;
+        }
+
+        if !falls_through(&instruction) {
+            return true;
+        }
+
+        address = end;
+    }
+
+    address < program.len()
+}
+
+/// Disassembles a program into lines of code and data, in address order.
+///
+/// Jumps through memory can't be followed, so any stretch the program never reaches
+/// that still decodes as a block of instructions is taken to be code too.
+pub fn disassemble(program: &[Value]) -> Vec<Line> {
+    let mut code = BTreeMap::new();
+    let mut labels = BTreeSet::new();
+
+    explore(program, 0, &mut code, &mut labels);
+
+    let mut address = 0;
+
+    while address < program.len() {
+        if let Some(instruction) = code.get(&address) {
+            address += instruction.size();
+        } else if is_block(program, address, &code) {
+            explore(program, address, &mut code, &mut labels);
+        } else {
+            address += 1;
+        }
+    }
+
+    let mut lines = vec![];
+    let mut address = 0;
+
+    while address < program.len() {
+        if let Some(instruction) = code.get(&address) {
+            let size = instruction.size();
+
+            lines.push(Line {
+                address,
+                label: labels.contains(&address),
+                item: Item::Code(instruction.clone()),
+                raw: program[address..address + size].to_vec(),
+            });
+
+            address += size;
+            continue;
+        }
+
+        // data runs until the next instruction or label, a line at a time
+        let end = (address + 1..program.len())
+            .take(DATA_PER_LINE - 1)
+            .find(|next| code.contains_key(next) || labels.contains(next))
+            .unwrap_or(program.len().min(address + DATA_PER_LINE));
+        let values = program[address..end].to_vec();
+
+        lines.push(Line {
+            address,
+            label: labels.contains(&address),
+            item: Item::Data(values.clone()),
+            raw: values,
+        });
+
+        address = end;
+    }
+
+    lines
+}
+
+/// Disassembles a program into an annotated listing, one line per instruction with its
+/// address and the values it was decoded from
+pub fn listing(program: &[Value]) -> String {
+    let mut listing = String::new();
+
+    for line in disassemble(program) {
+        if line.label {
+            listing += &format!("{}:\n", label(line.address));
+        }
+
+        let text = match &line.item {
+            Item::Code(instruction) => match jump_target(instruction) {
+                Some(target) => {
+                    let params = instruction.params();
+                    format!(
+                        "{} {}, #{}",
+                        instruction.mnemonic(),
+                        params[0],
+                        label(target)
+                    )
+                }
+                None => instruction.to_string(),
+            },
+            Item::Data(values) => {
+                let values: Vec<String> = values.iter().map(Value::to_string).collect();
+                format!("data {}", values.join(", "))
+            }
+        };
+
+        let raw: Vec<String> = line.raw.iter().map(Value::to_string).collect();
+
+        match line.item {
+            Item::Code(_) => {
+                listing += &format!("{:04}  {text:<32} ; {}\n", line.address, raw.join(","))
+            }
+            Item::Data(_) => listing += &format!("{:04}  {text}\n", line.address),
+        }
+    }
+
+    listing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_parameter_modes() {
+        let instruction = Instruction::try_from(&[21101, 4, -3, 7][..]).unwrap();
+
+        assert_eq!(instruction.to_string(), "add #4, #-3, @7");
+        assert_eq!(Instruction::Terminal.to_string(), "hlt");
+    }
+
+    #[test]
+    fn separates_code_from_data() {
+        let program = [1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+        let lines = disassemble(&program);
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[2].item, Item::Code(Instruction::Terminal));
+        assert_eq!(lines[3].address, 9);
+        assert_eq!(lines[3].item, Item::Data(vec![30, 40, 50]));
+    }
+
+    #[test]
+    fn follows_jumps() {
+        // jumps over the data at 3 and 4, with the target at 5
+        let program = [1105, 1, 5, 7, 8, 104, 3, 99];
+        let lines = disassemble(&program);
+
+        assert_eq!(lines[1].item, Item::Data(vec![7, 8]));
+        assert!(lines[2].label);
+        assert_eq!(lines[2].address, 5);
+
+        assert_eq!(
+            listing(&program),
+            "0000  jt #1, #L0005                    ; 1105,1,5\n\
+             0003  data 7, 8\n\
+             L0005:\n\
+             0005  out #3                           ; 104,3\n\
+             0007  hlt                              ; 99\n"
+        );
+    }
+
+    #[test]
+    fn lists_undecodable_values_as_data() {
+        let program = [104, 1, 99, 42, 7, 1];
+        let lines = disassemble(&program);
+
+        assert_eq!(lines[2].item, Item::Data(vec![42, 7, 1]));
+    }
+}
@@ -17,12 +17,33 @@ pub enum Error {
     #[error("Out of bound write for op: {0:?}")]
     OutOfBoundsOpParamsWrite(Instruction),
 
+    #[error("Out of bounds jump to address: {0}")]
+    OutOfBoundsJump(Value),
+
+    #[error("Input instruction with no input available")]
+    NoInput,
+
+    #[error("Packet sent to unknown address: {0}")]
+    UnknownAddress(Value),
+
     #[error("Unsufficient arugments for opcode: {0}")]
     ParseErrorOutOfBoundsArguments(Value),
 
     #[error("Unrecognised op code: {0}")]
     ParseErrorUnsupportOpCode(Value),
 
+    #[error("Unsupported parameter modes for op code: {0}")]
+    ParseErrorUnsupportedMode(Value),
+
+    #[error("Step limit of {0} instructions exceeded")]
+    StepLimitExceeded(usize),
+
+    #[error("Invalid snapshot: {0}")]
+    InvalidSnapshot(&'static str),
+
+    #[error("Assembly error on line {line}: {message}")]
+    AssemblyError { line: usize, message: String },
+
     #[error("No op code provided, memory might not be initialised")]
     ParseErrorNoOpCodeProvided,
 }
@@ -1,13 +1,50 @@
+use std::collections::VecDeque;
+
 use crate::error::{Error, IntCodeResult};
+use crate::observe::Observer;
 
 pub type Cursor = usize;
 pub type Value = isize;
 
+/// How far memory may grow past the end of the program
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoryLimit {
+    /// Memory grows, zero filled, to whatever address the program uses
+    #[default]
+    Unbounded,
+    /// Memory grows up to this many values, accessing any address past it is an error
+    Capped(usize),
+}
+
+impl MemoryLimit {
+    fn allows(&self, address: Cursor) -> bool {
+        match self {
+            MemoryLimit::Unbounded => true,
+            MemoryLimit::Capped(size) => address < *size,
+        }
+    }
+}
+
+/// Why an executor stopped running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// The program is waiting on input, running again retries the input instruction
+    NeedsInput,
+    /// The program output a value
+    Output(Value),
+    /// The program halted, running again does nothing
+    Halted,
+}
+
 /// Intcode executor. Will exceute intcode programs
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct IntCodeExecutor {
-    memory: Vec<Value>,
-    cursor: Cursor,
+    pub(crate) memory: Vec<Value>,
+    pub(crate) memory_limit: MemoryLimit,
+    pub(crate) cursor: Cursor,
+    pub(crate) relative_base: Value,
+    pub(crate) input: VecDeque<Value>,
+    pub(crate) output: Vec<Value>,
 }
 
 impl IntCodeExecutor {
@@ -19,98 +56,428 @@ impl IntCodeExecutor {
         }
     }
 
+    /// Sets how far memory may grow past the end of the program
+    pub fn with_memory_limit(mut self, limit: MemoryLimit) -> Self {
+        self.memory_limit = limit;
+        self
+    }
+
     /// Modify Intcode program in place within an executor
     pub fn modify_with_address(&mut self, address: Cursor, value: Value) -> IntCodeResult<()> {
-        if let Some(elem) = self.memory.get_mut(address) {
-            *elem = value;
-        } else {
-            Err(Error::OutOfBoundsWrite(address))?
+        self.write_memory(address, &[value])
+    }
+
+    /// Overwrites memory from an address onwards, growing it if the limit allows
+    pub fn write_memory(&mut self, address: Cursor, values: &[Value]) -> IntCodeResult<()> {
+        for (offset, value) in values.iter().enumerate() {
+            // writing past the last address there could be is out of bounds too
+            let target = address
+                .checked_add(offset)
+                .ok_or(Error::OutOfBoundsWrite(address))?;
+
+            if let Some(elem) = self.cell_mut(target) {
+                *elem = *value;
+            } else {
+                Err(Error::OutOfBoundsWrite(target))?
+            }
         }
 
         Ok(())
     }
 
-    /// Consume Intcode executor to get the result
-    pub fn execute(mut self) -> IntCodeResult<Value> {
+    /// Queues values for the program's input instructions to read
+    pub fn with_input(mut self, values: impl IntoIterator<Item = Value>) -> Self {
+        self.input.extend(values);
+        self
+    }
+
+    /// Queue a value for the program's input instructions to read
+    pub fn push_input(&mut self, value: Value) {
+        self.input.push_back(value);
+    }
+
+    /// Number of queued values the program hasn't read yet
+    pub fn input_len(&self) -> usize {
+        self.input.len()
+    }
+
+    /// Values output by [`run`](Self::run) and not yet taken
+    pub fn output(&self) -> &[Value] {
+        &self.output
+    }
+
+    /// Takes the values output by [`run`](Self::run) so far, leaving the queue empty
+    pub fn take_output(&mut self) -> Vec<Value> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// The program's memory as it currently is
+    pub fn memory(&self) -> &[Value] {
+        &self.memory
+    }
+
+    /// Address of the next instruction
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Base address of parameters in relative mode
+    pub fn relative_base(&self) -> Value {
+        self.relative_base
+    }
+
+    /// Decodes the next instruction
+    pub fn instruction(&self) -> IntCodeResult<Instruction> {
+        if self.cursor >= self.memory.len() {
+            Err(Error::OutOfBoundsOpCodeRead(self.cursor))?;
+        }
+
+        self.memory[self.cursor..].try_into()
+    }
+
+    /// Executes the next instruction, returning the state if it stopped the program
+    /// like [`run_until`](Self::run_until) would
+    pub fn step(&mut self) -> IntCodeResult<Option<State>> {
+        self.step_with(&mut ())
+    }
+
+    /// [`step`](Self::step), telling an observer what the instruction does
+    pub fn step_with(&mut self, observer: &mut impl Observer) -> IntCodeResult<Option<State>> {
+        let op = self.instruction()?;
+
+        // waiting on input doesn't execute the instruction
+        if matches!(op, Instruction::Input(_)) && self.input.is_empty() {
+            return Ok(Some(State::NeedsInput));
+        }
+
+        observer.instruction(self.cursor, &op)?;
+
+        self.execute_op(op, observer)
+    }
+
+    /// Run the program in place until it outputs a value, needs input it doesn't
+    /// have, or halts.
+    ///
+    /// Output values are returned in the state rather than queued.
+    pub fn run_until(&mut self) -> IntCodeResult<State> {
+        self.run_until_with(&mut ())
+    }
+
+    /// [`run_until`](Self::run_until), telling an observer what each instruction does
+    pub fn run_until_with(&mut self, observer: &mut impl Observer) -> IntCodeResult<State> {
         loop {
-            if self.cursor > self.memory.len() - 1 {
-                Err(Error::OutOfBoundsOpCodeRead(self.cursor))?;
+            if let Some(state) = self.step_with(observer)? {
+                return Ok(state);
             }
+        }
+    }
 
-            let slice = &self.memory[self.cursor..];
-
-            let op = slice.try_into()?;
+    /// Run the program in place until it halts or needs input it doesn't have,
+    /// queueing its output
+    pub fn run(&mut self) -> IntCodeResult<State> {
+        self.run_with(&mut ())
+    }
 
-            if let Some(res) = self.execute_op(op)? {
-                return Ok(res);
+    /// [`run`](Self::run), telling an observer what each instruction does
+    pub fn run_with(&mut self, observer: &mut impl Observer) -> IntCodeResult<State> {
+        loop {
+            match self.run_until_with(observer)? {
+                State::Output(value) => self.output.push(value),
+                state => return Ok(state),
             }
         }
     }
 
-    fn execute_op(&mut self, op: Instruction) -> IntCodeResult<Option<Value>> {
+    /// Consume Intcode executor to get the result
+    pub fn execute(self) -> IntCodeResult<Value> {
+        self.execute_with(&mut ())
+    }
+
+    /// [`execute`](Self::execute), telling an observer what each instruction does. With a
+    /// [`StepLimit`](crate::observe::StepLimit) a program that never halts is an error
+    /// rather than running forever
+    pub fn execute_with(mut self, observer: &mut impl Observer) -> IntCodeResult<Value> {
+        match self.run_with(observer)? {
+            State::Halted => Ok(self.memory[0]),
+            _ => Err(Error::NoInput),
+        }
+    }
+
+    /// Executes an instruction, returns the state to stop in if it shouldn't continue
+    fn execute_op(
+        &mut self,
+        op: Instruction,
+        observer: &mut impl Observer,
+    ) -> IntCodeResult<Option<State>> {
+        let next = self.cursor + op.size();
+
         match op {
             Instruction::Add(param1, param2, param3) => {
-                let value = if let (Some(x), Some(y)) =
-                    (self.memory.get(param1), self.memory.get(param2))
-                {
-                    x + y
-                } else {
-                    Err(Error::OutOfBoundsOpParamsRead(op.clone()))?
+                let value = self.read(&op, param1, observer)? + self.read(&op, param2, observer)?;
+                self.write(&op, param3, value, observer)?;
+            }
+            Instruction::Multiply(param1, param2, param3) => {
+                let value = self.read(&op, param1, observer)? * self.read(&op, param2, observer)?;
+                self.write(&op, param3, value, observer)?;
+            }
+            Instruction::Input(param) => {
+                let Some(value) = self.input.pop_front() else {
+                    return Ok(Some(State::NeedsInput));
                 };
 
-                if let Some(elem) = self.memory.get_mut(param3) {
-                    *elem = value
-                } else {
-                    Err(Error::OutOfBoundsOpParamsWrite(op.clone()))?
-                };
+                observer.input(value);
+                self.write(&op, param, value, observer)?;
+            }
+            Instruction::Output(param) => {
+                let value = self.read(&op, param, observer)?;
+                observer.output(value);
+                self.cursor = next;
 
-                self.cursor += 4;
-                Ok(None)
+                return Ok(Some(State::Output(value)));
             }
-            Instruction::Multiply(param1, param2, param3) => {
-                let value = if let (Some(x), Some(y)) =
-                    (self.memory.get(param1), self.memory.get(param2))
-                {
-                    x * y
-                } else {
-                    Err(Error::OutOfBoundsOpParamsRead(op.clone()))?
-                };
+            Instruction::JumpIfTrue(param1, param2) | Instruction::JumpIfFalse(param1, param2) => {
+                let jump_if = matches!(op, Instruction::JumpIfTrue(..));
 
-                if let Some(elem) = self.memory.get_mut(param3) {
-                    *elem = value
-                } else {
-                    Err(Error::OutOfBoundsOpParamsWrite(op.clone()))?
-                };
+                if (self.read(&op, param1, observer)? != 0) == jump_if {
+                    let target = self.read(&op, param2, observer)?;
 
-                self.cursor += 4;
-                Ok(None)
+                    self.cursor =
+                        Cursor::try_from(target).map_err(|_| Error::OutOfBoundsJump(target))?;
+                    return Ok(None);
+                }
+            }
+            Instruction::LessThan(param1, param2, param3) => {
+                let value = self.read(&op, param1, observer)? < self.read(&op, param2, observer)?;
+                self.write(&op, param3, value as Value, observer)?;
+            }
+            Instruction::Equals(param1, param2, param3) => {
+                let value =
+                    self.read(&op, param1, observer)? == self.read(&op, param2, observer)?;
+                self.write(&op, param3, value as Value, observer)?;
             }
-            Instruction::Terminal => Ok(Some(self.memory[0])),
+            Instruction::AdjustRelativeBase(param) => {
+                self.relative_base += self.read(&op, param, observer)?;
+            }
+            Instruction::Terminal => return Ok(Some(State::Halted)),
+        }
+
+        self.cursor = next;
+
+        Ok(None)
+    }
+
+    /// Address a parameter refers to, if it is one memory can have
+    fn address(&self, param: Param) -> Option<Cursor> {
+        let address = match param.mode {
+            Mode::Position | Mode::Immediate => param.value,
+            Mode::Relative => self.relative_base.checked_add(param.value)?,
+        };
+
+        Cursor::try_from(address)
+            .ok()
+            .filter(|address| self.memory_limit.allows(*address))
+    }
+
+    /// Memory cell at an address, growing memory to reach it if the limit allows and
+    /// there is room for it
+    fn cell_mut(&mut self, address: Cursor) -> Option<&mut Value> {
+        if !self.memory_limit.allows(address) {
+            return None;
+        }
+
+        if address >= self.memory.len() {
+            let size = address.checked_add(1)?;
+
+            self.memory
+                .try_reserve_exact(size - self.memory.len())
+                .ok()?;
+            self.memory.resize(size, 0);
         }
+
+        self.memory.get_mut(address)
+    }
+
+    fn read(
+        &self,
+        op: &Instruction,
+        param: Param,
+        observer: &mut impl Observer,
+    ) -> IntCodeResult<Value> {
+        if param.mode == Mode::Immediate {
+            return Ok(param.value);
+        }
+
+        let address = self
+            .address(param)
+            .ok_or_else(|| Error::OutOfBoundsOpParamsRead(op.clone()))?;
+
+        // memory past the end of the program reads as zero without growing it
+        let value = self.memory.get(address).copied().unwrap_or(0);
+        observer.read(address, value);
+
+        Ok(value)
+    }
+
+    fn write(
+        &mut self,
+        op: &Instruction,
+        param: Param,
+        value: Value,
+        observer: &mut impl Observer,
+    ) -> IntCodeResult<()> {
+        // the decoder rejects writes in immediate mode
+        let address = self
+            .address(param)
+            .ok_or_else(|| Error::OutOfBoundsOpParamsWrite(op.clone()))?;
+        let elem = self
+            .cell_mut(address)
+            .ok_or_else(|| Error::OutOfBoundsOpParamsWrite(op.clone()))?;
+
+        *elem = value;
+        observer.write(address, value);
+
+        Ok(())
     }
 }
 
-#[derive(Debug, Clone)]
+/// How an instruction's parameter is interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The parameter is the address of the value
+    Position,
+    /// The parameter is the value itself
+    Immediate,
+    /// The parameter is the address of the value, relative to the relative base
+    Relative,
+}
+
+/// Parameter of an instruction, with its mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub mode: Mode,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Instruction {
-    Add(Cursor, Cursor, Cursor),
-    Multiply(Cursor, Cursor, Cursor),
+    Add(Param, Param, Param),
+    Multiply(Param, Param, Param),
+    Input(Param),
+    Output(Param),
+    JumpIfTrue(Param, Param),
+    JumpIfFalse(Param, Param),
+    LessThan(Param, Param, Param),
+    Equals(Param, Param, Param),
+    AdjustRelativeBase(Param),
     Terminal,
 }
 
+impl Instruction {
+    /// Number of values the instruction takes up in memory, including its op code
+    pub fn size(&self) -> usize {
+        match self {
+            Instruction::Add(..)
+            | Instruction::Multiply(..)
+            | Instruction::LessThan(..)
+            | Instruction::Equals(..) => 4,
+            Instruction::JumpIfTrue(..) | Instruction::JumpIfFalse(..) => 3,
+            Instruction::Input(..)
+            | Instruction::Output(..)
+            | Instruction::AdjustRelativeBase(..) => 2,
+            Instruction::Terminal => 1,
+        }
+    }
+
+    /// Short name of the instruction, as used by the disassembler
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Add(..) => "add",
+            Instruction::Multiply(..) => "mul",
+            Instruction::Input(..) => "in",
+            Instruction::Output(..) => "out",
+            Instruction::JumpIfTrue(..) => "jt",
+            Instruction::JumpIfFalse(..) => "jf",
+            Instruction::LessThan(..) => "lt",
+            Instruction::Equals(..) => "eq",
+            Instruction::AdjustRelativeBase(..) => "arb",
+            Instruction::Terminal => "hlt",
+        }
+    }
+
+    /// The instruction's parameters, in order
+    pub fn params(&self) -> Vec<Param> {
+        match *self {
+            Instruction::Add(param1, param2, param3)
+            | Instruction::Multiply(param1, param2, param3)
+            | Instruction::LessThan(param1, param2, param3)
+            | Instruction::Equals(param1, param2, param3) => vec![param1, param2, param3],
+            Instruction::JumpIfTrue(param1, param2) | Instruction::JumpIfFalse(param1, param2) => {
+                vec![param1, param2]
+            }
+            Instruction::Input(param)
+            | Instruction::Output(param)
+            | Instruction::AdjustRelativeBase(param) => vec![param],
+            Instruction::Terminal => vec![],
+        }
+    }
+}
+
 impl TryFrom<&[Value]> for Instruction {
     type Error = Error;
     fn try_from(ops: &[Value]) -> IntCodeResult<Self> {
-        Ok(match ops {
-            [1, param1, param2, param3, ..] => {
-                Instruction::Add(*param1 as Cursor, *param2 as Cursor, *param3 as Cursor)
-            }
-            [2, param1, param2, param3, ..] => {
-                Instruction::Multiply(*param1 as Cursor, *param2 as Cursor, *param3 as Cursor)
-            }
-            [99, ..] => Instruction::Terminal,
-            [1, ..] | [2, ..] => Err(Error::ParseErrorOutOfBoundsArguments(ops[0]))?,
-            [code, ..] => Err(Error::ParseErrorUnsupportOpCode(*code))?,
-            [] => Err(Error::ParseErrorNoOpCodeProvided)?,
+        let [code, params @ ..] = ops else {
+            return Err(Error::ParseErrorNoOpCodeProvided);
+        };
+
+        // parameter count and which parameter, if any, is written to
+        let (count, written) = match code % 100 {
+            1 | 2 | 7 | 8 => (3, Some(2)),
+            3 => (1, Some(0)),
+            4 | 9 => (1, None),
+            5 | 6 => (2, None),
+            99 => (0, None),
+            _ => Err(Error::ParseErrorUnsupportOpCode(*code))?,
+        };
+
+        if params.len() < count {
+            Err(Error::ParseErrorOutOfBoundsArguments(*code))?;
+        }
+
+        let mut modes = code / 100;
+        let mut decoded = [Param {
+            mode: Mode::Position,
+            value: 0,
+        }; 3];
+
+        for (index, param) in decoded.iter_mut().take(count).enumerate() {
+            param.value = params[index];
+            param.mode = match modes % 10 {
+                0 => Mode::Position,
+                1 if written != Some(index) => Mode::Immediate,
+                2 => Mode::Relative,
+                _ => Err(Error::ParseErrorUnsupportedMode(*code))?,
+            };
+
+            modes /= 10;
+        }
+
+        if modes != 0 {
+            Err(Error::ParseErrorUnsupportedMode(*code))?;
+        }
+
+        let [param1, param2, param3] = decoded;
+
+        Ok(match code % 100 {
+            1 => Instruction::Add(param1, param2, param3),
+            2 => Instruction::Multiply(param1, param2, param3),
+            3 => Instruction::Input(param1),
+            4 => Instruction::Output(param1),
+            5 => Instruction::JumpIfTrue(param1, param2),
+            6 => Instruction::JumpIfFalse(param1, param2),
+            7 => Instruction::LessThan(param1, param2, param3),
+            8 => Instruction::Equals(param1, param2, param3),
+            9 => Instruction::AdjustRelativeBase(param1),
+            _ => Instruction::Terminal,
         })
     }
 }
@@ -118,6 +485,7 @@ impl TryFrom<&[Value]> for Instruction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::asm::assemble;
 
     #[test]
     fn original_examples1() {
@@ -141,47 +509,303 @@ mod tests {
         assert_eq!(3500, code);
     }
 
+    /// Runs a chain of amplifiers, each one given its phase setting then the
+    /// previous amplifier's output signal
+    fn amplify(program: &[Value], phases: &[Value]) -> Value {
+        phases.iter().fold(0, |signal, &phase| {
+            let mut executor = IntCodeExecutor::new(program);
+            executor.push_input(phase);
+            executor.push_input(signal);
+            executor.run().expect("Error running amplifier");
+
+            executor.output()[0]
+        })
+    }
+
+    /// Runs a program with a single input, returning its outputs
+    fn run_with_input(program: &[Value], input: Value) -> Vec<Value> {
+        let mut executor = IntCodeExecutor::new(program);
+        executor.push_input(input);
+        executor.run().expect("Error running program");
+
+        executor.output().to_vec()
+    }
+
     #[test]
-    #[ignore = "todo"]
     fn original_examples3() {
         let ops = [
             3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
         ];
 
-        let code = IntCodeExecutor::new(&ops)
-            .execute()
-            .expect("Error unwrapping op");
-
-        assert_eq!(code, 43210);
+        assert_eq!(amplify(&ops, &[4, 3, 2, 1, 0]), 43210);
     }
 
     #[test]
-    #[ignore = "todo"]
     fn original_examples4() {
         let ops = [
             3, 23, 3, 24, 1002, 24, 10, 24, 1002, 23, -1, 23, 101, 5, 23, 23, 1, 24, 23, 23, 4, 23,
             99, 0, 0,
         ];
 
-        let code = IntCodeExecutor::new(&ops)
-            .execute()
-            .expect("Error unwrapping op");
-
-        assert_eq!(code, 54321);
+        assert_eq!(amplify(&ops, &[0, 1, 2, 3, 4]), 54321);
     }
 
     #[test]
-    #[ignore = "todo"]
     fn original_examples5() {
         let ops = [
             3, 31, 3, 32, 1002, 32, 10, 32, 1001, 31, -2, 31, 1007, 31, 0, 33, 1002, 33, 7, 33, 1,
             33, 31, 31, 1, 32, 31, 31, 4, 31, 99, 0, 0, 0,
         ];
 
-        let code = IntCodeExecutor::new(&ops)
-            .execute()
-            .expect("Error unwrapping op");
+        assert_eq!(amplify(&ops, &[1, 0, 4, 3, 2]), 65210);
+    }
+
+    #[test]
+    fn decodes_parameter_modes() {
+        let immediate = |value| Param {
+            mode: Mode::Immediate,
+            value,
+        };
+        let position = |value| Param {
+            mode: Mode::Position,
+            value,
+        };
+
+        assert_eq!(
+            Instruction::try_from(&[1002, 4, 3, 4][..]).unwrap(),
+            Instruction::Multiply(position(4), immediate(3), position(4))
+        );
+        assert_eq!(
+            Instruction::try_from(&[1105, 1, 9][..]).unwrap(),
+            Instruction::JumpIfTrue(immediate(1), immediate(9))
+        );
+        assert!(matches!(
+            Instruction::try_from(&[103, 0][..]),
+            Err(Error::ParseErrorUnsupportedMode(103))
+        ));
+        assert_eq!(
+            Instruction::try_from(&[21201, -1, 3, 5][..]).unwrap(),
+            Instruction::Add(
+                Param {
+                    mode: Mode::Relative,
+                    value: -1
+                },
+                immediate(3),
+                Param {
+                    mode: Mode::Relative,
+                    value: 5
+                }
+            )
+        );
+        assert!(matches!(
+            Instruction::try_from(&[301, 0, 0, 0][..]),
+            Err(Error::ParseErrorUnsupportedMode(301))
+        ));
+        assert!(matches!(
+            Instruction::try_from(&[10001, 0, 0, 0][..]),
+            Err(Error::ParseErrorUnsupportedMode(10001))
+        ));
+        assert!(matches!(
+            Instruction::try_from(&[1108, 0][..]),
+            Err(Error::ParseErrorOutOfBoundsArguments(1108))
+        ));
+    }
+
+    #[test]
+    fn immediate_mode_examples() {
+        let mut executor = IntCodeExecutor::new(&[1002, 4, 3, 4, 33]);
+        executor.run().unwrap();
+
+        assert_eq!(executor.memory()[4], 99);
+
+        let mut executor = IntCodeExecutor::new(&[1101, 100, -1, 4, 0]);
+        executor.run().unwrap();
+
+        assert_eq!(executor.memory()[4], 99);
+    }
+
+    #[test]
+    fn input_output_examples() {
+        assert_eq!(run_with_input(&[3, 0, 4, 0, 99], 42), [42]);
+        assert!(matches!(
+            IntCodeExecutor::new(&[3, 0, 4, 0, 99]).execute(),
+            Err(Error::NoInput)
+        ));
+    }
+
+    #[test]
+    fn comparison_examples() {
+        let equal_position = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        let less_position = [3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8];
+        let equal_immediate = [3, 3, 1108, -1, 8, 3, 4, 3, 99];
+        let less_immediate = [3, 3, 1107, -1, 8, 3, 4, 3, 99];
+
+        for (input, equal, less) in [(7, 0, 1), (8, 1, 0), (9, 0, 0)] {
+            assert_eq!(run_with_input(&equal_position, input), [equal]);
+            assert_eq!(run_with_input(&less_position, input), [less]);
+            assert_eq!(run_with_input(&equal_immediate, input), [equal]);
+            assert_eq!(run_with_input(&less_immediate, input), [less]);
+        }
+    }
+
+    #[test]
+    fn jump_examples() {
+        let position = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+        let immediate = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+
+        for (input, output) in [(0, 0), (5, 1)] {
+            assert_eq!(run_with_input(&position, input), [output]);
+            assert_eq!(run_with_input(&immediate, input), [output]);
+        }
+    }
+
+    #[test]
+    fn larger_example() {
+        let ops = [
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+
+        assert_eq!(run_with_input(&ops, 7), [999]);
+        assert_eq!(run_with_input(&ops, 8), [1000]);
+        assert_eq!(run_with_input(&ops, 9), [1001]);
+    }
+
+    #[test]
+    fn relative_mode_examples() {
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let mut executor = IntCodeExecutor::new(&quine);
+        executor.run().unwrap();
+
+        assert_eq!(executor.output(), quine);
+
+        let mut executor = IntCodeExecutor::new(&[1102, 34915192, 34915192, 7, 4, 7, 99, 0]);
+        executor.run().unwrap();
+
+        assert_eq!(executor.output()[0].to_string().len(), 16);
+
+        let mut executor = IntCodeExecutor::new(&[104, 1125899906842624, 99]);
+        executor.run().unwrap();
+
+        assert_eq!(executor.output(), [1125899906842624]);
+    }
+
+    #[test]
+    fn relative_base_moves_between_reads() {
+        // sums the inputs onto a stack below address 100, walking the relative base up
+        let program = assemble(
+            "
+                    arb #100
+            next:   in @0
+                    jf @0, #done
+                    add @0, total, total
+                    arb #1
+                    jt #1, #next
+            done:   out total
+                    arb #-100
+                    out @100
+                    hlt
+            total:  data 0
+            ",
+        )
+        .unwrap();
+
+        let mut executor = IntCodeExecutor::new(&program).with_input([4, 5, 6, 0]);
+        executor.run().unwrap();
+
+        assert_eq!(executor.output(), [15, 0]);
+        assert_eq!(&executor.memory()[100..103], [4, 5, 6]);
+    }
+
+    #[test]
+    fn memory_grows_past_the_program() {
+        // reads address 1000, then writes its value plus one to address 2000
+        let ops = [1001, 1000, 1, 2000, 4, 2000, 99];
+
+        let mut executor = IntCodeExecutor::new(&ops);
+        executor.run().unwrap();
+
+        assert_eq!(executor.output(), [1]);
+        assert_eq!(executor.memory().len(), 2001);
+
+        let mut executor = IntCodeExecutor::new(&ops).with_memory_limit(MemoryLimit::Capped(1500));
+
+        assert!(matches!(
+            executor.run(),
+            Err(Error::OutOfBoundsOpParamsWrite(_))
+        ));
+        assert!(matches!(
+            executor.modify_with_address(1500, 1),
+            Err(Error::OutOfBoundsWrite(1500))
+        ));
+        assert!(executor.modify_with_address(1499, 1).is_ok());
+    }
+
+    #[test]
+    fn rejects_writes_past_the_last_address() {
+        let mut executor = IntCodeExecutor::new(&[99]);
+
+        assert!(matches!(
+            executor.write_memory(usize::MAX, &[1, 2]),
+            Err(Error::OutOfBoundsWrite(usize::MAX))
+        ));
+        assert!(matches!(
+            executor.modify_with_address(usize::MAX, 1),
+            Err(Error::OutOfBoundsWrite(usize::MAX))
+        ));
+
+        // more memory than could ever be allocated
+        assert!(matches!(
+            executor.modify_with_address(1 << 60, 1),
+            Err(Error::OutOfBoundsWrite(_))
+        ));
+        assert_eq!(executor.memory(), [99]);
+    }
+
+    #[test]
+    fn pauses_for_input_and_output() {
+        let mut executor = IntCodeExecutor::new(&[3, 0, 4, 0, 99]);
+
+        assert_eq!(executor.run_until().unwrap(), State::NeedsInput);
+        assert_eq!(executor.run_until().unwrap(), State::NeedsInput);
+
+        executor.push_input(7);
+
+        assert_eq!(executor.run_until().unwrap(), State::Output(7));
+        assert_eq!(executor.run_until().unwrap(), State::Halted);
+        assert_eq!(executor.run_until().unwrap(), State::Halted);
+        assert!(executor.output().is_empty());
+    }
+
+    #[test]
+    fn feedback_loop_example() {
+        let ops = [
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+
+        let mut amplifiers: Vec<IntCodeExecutor> = [9, 8, 7, 6, 5]
+            .into_iter()
+            .map(|phase| IntCodeExecutor::new(&ops).with_input([phase]))
+            .collect();
+
+        let mut signal = 0;
+
+        'feedback: loop {
+            for amplifier in amplifiers.iter_mut() {
+                amplifier.push_input(signal);
+
+                match amplifier.run_until().unwrap() {
+                    State::Output(value) => signal = value,
+                    State::Halted => break 'feedback,
+                    State::NeedsInput => panic!("amplifier is missing its input"),
+                }
+            }
+        }
 
-        assert_eq!(code, 65210);
+        assert_eq!(signal, 139629729);
     }
 }
@@ -1,2 +1,9 @@
+pub mod ascii;
+pub mod asm;
+pub mod debug;
+pub mod disasm;
 pub mod error;
 pub mod intcode;
+pub mod network;
+pub mod observe;
+pub mod snapshot;
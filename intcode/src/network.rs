@@ -0,0 +1,424 @@
+//! Run several intcode machines together, passing messages between them
+//!
+//! Machines take turns in address order, each running until it blocks on input or
+//! halts, so a network always runs the same way for the same programs and inputs.
+//! Packets sent to an address outside the network go to a [`Nat`], which also
+//! decides what happens when the network goes idle.
+
+use std::ops::ControlFlow;
+
+use crate::error::{Error, IntCodeResult};
+use crate::intcode::{IntCodeExecutor, State, Value};
+
+/// Where machines send the values they output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Addressing {
+    /// Every value goes to the next machine, the last machine's go to the NAT
+    Chain,
+    /// Values are output as packets, an address followed by `payload` values
+    Packets { payload: usize },
+}
+
+/// Values sent to the machine at an address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub address: Value,
+    pub payload: Vec<Value>,
+}
+
+/// Handles packets sent outside the network, and the network going idle
+pub trait Nat {
+    /// A packet was sent to an address outside the network. Continue with packets to
+    /// send on, or break with the network's result
+    fn receive(&mut self, packet: Packet) -> ControlFlow<Value, Vec<Packet>>;
+
+    /// Nothing was sent and no running machine had input for a whole round. Continue
+    /// with packets to send, the network stops when there are none
+    fn idle(&mut self) -> ControlFlow<Value, Vec<Packet>> {
+        ControlFlow::Continue(vec![])
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Machine {
+    executor: IntCodeExecutor,
+    halted: bool,
+    /// Values output towards the next packet
+    pending: Vec<Value>,
+}
+
+/// Machines that run round-robin, sending each other packets
+#[derive(Debug, Clone)]
+pub struct Network {
+    machines: Vec<Machine>,
+    addressing: Addressing,
+    empty_input: Option<Value>,
+}
+
+impl Network {
+    /// Network of machines, addressed by their position starting from 0
+    pub fn new(
+        machines: impl IntoIterator<Item = IntCodeExecutor>,
+        addressing: Addressing,
+    ) -> Self {
+        Network {
+            machines: machines
+                .into_iter()
+                .map(|executor| Machine {
+                    executor,
+                    halted: false,
+                    pending: vec![],
+                })
+                .collect(),
+            addressing,
+            empty_input: None,
+        }
+    }
+
+    /// Gives each machine its address as its first input
+    pub fn with_addresses(mut self) -> Self {
+        for (address, machine) in self.machines.iter_mut().enumerate() {
+            machine.executor.push_input(address as Value);
+        }
+
+        self
+    }
+
+    /// Value a machine reads, once per turn, when it wants input and has none queued.
+    /// Without one, machines block until a packet arrives
+    pub fn with_empty_input(mut self, value: Value) -> Self {
+        self.empty_input = Some(value);
+        self
+    }
+
+    /// The machine at an address
+    pub fn machine(&self, address: usize) -> Option<&IntCodeExecutor> {
+        self.machines.get(address).map(|machine| &machine.executor)
+    }
+
+    /// Queues a packet's payload as input to the machine at its address. Packets to
+    /// halted machines are dropped
+    pub fn send(&mut self, packet: Packet) -> IntCodeResult<()> {
+        let machine = usize::try_from(packet.address)
+            .ok()
+            .and_then(|address| self.machines.get_mut(address))
+            .ok_or(Error::UnknownAddress(packet.address))?;
+
+        if !machine.halted {
+            for value in packet.payload {
+                machine.executor.push_input(value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends a packet to a machine, or to the NAT when it is addressed outside the network
+    fn route(&mut self, packet: Packet, nat: &mut impl Nat) -> IntCodeResult<ControlFlow<Value>> {
+        let inside =
+            usize::try_from(packet.address).is_ok_and(|address| address < self.machines.len());
+
+        if inside {
+            self.send(packet)?;
+            return Ok(ControlFlow::Continue(()));
+        }
+
+        match nat.receive(packet) {
+            ControlFlow::Break(result) => Ok(ControlFlow::Break(result)),
+            ControlFlow::Continue(packets) => {
+                for packet in packets {
+                    self.send(packet)?;
+                }
+
+                Ok(ControlFlow::Continue(()))
+            }
+        }
+    }
+
+    /// The packet a machine has finished outputting, if it has
+    fn take_packet(&mut self, address: usize) -> Option<Packet> {
+        let pending = &mut self.machines[address].pending;
+
+        match self.addressing {
+            Addressing::Chain => Some(Packet {
+                address: address as Value + 1,
+                payload: std::mem::take(pending),
+            }),
+            Addressing::Packets { payload } if pending.len() > payload => {
+                let mut values = std::mem::take(pending);
+                let address = values.remove(0);
+
+                Some(Packet {
+                    address,
+                    payload: values,
+                })
+            }
+            Addressing::Packets { .. } => None,
+        }
+    }
+
+    /// Gives every running machine a turn. Continues with whether the network was idle
+    pub fn round(&mut self, nat: &mut impl Nat) -> IntCodeResult<ControlFlow<Value, bool>> {
+        let mut idle = true;
+
+        for address in 0..self.machines.len() {
+            if self.machines[address].halted {
+                continue;
+            }
+
+            if self.machines[address].executor.input_len() > 0 {
+                idle = false;
+            }
+
+            let mut empty_input = self.empty_input;
+
+            loop {
+                let machine = &mut self.machines[address];
+
+                match machine.executor.run_until()? {
+                    State::Output(value) => {
+                        idle = false;
+                        machine.pending.push(value);
+
+                        if let Some(packet) = self.take_packet(address)
+                            && let ControlFlow::Break(result) = self.route(packet, nat)?
+                        {
+                            return Ok(ControlFlow::Break(result));
+                        }
+                    }
+                    State::NeedsInput => match empty_input.take() {
+                        Some(value) => machine.executor.push_input(value),
+                        None => break,
+                    },
+                    State::Halted => {
+                        machine.halted = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(ControlFlow::Continue(idle))
+    }
+
+    /// Runs rounds until the NAT breaks with a result, or nothing more can happen because
+    /// every machine halted or the network is idle with nothing for the NAT to send
+    pub fn run(&mut self, nat: &mut impl Nat) -> IntCodeResult<Option<Value>> {
+        loop {
+            let idle = match self.round(nat)? {
+                ControlFlow::Break(result) => return Ok(Some(result)),
+                ControlFlow::Continue(idle) => idle,
+            };
+
+            if self.machines.iter().all(|machine| machine.halted) {
+                return Ok(None);
+            }
+
+            if !idle {
+                continue;
+            }
+
+            match nat.idle() {
+                ControlFlow::Break(result) => return Ok(Some(result)),
+                ControlFlow::Continue(packets) if packets.is_empty() => return Ok(None),
+                ControlFlow::Continue(packets) => {
+                    for packet in packets {
+                        if let ControlFlow::Break(result) = self.route(packet, nat)? {
+                            return Ok(Some(result));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Feeds everything sent past the end of a chain back to its first machine, keeping the
+/// last value
+#[derive(Debug, Clone, Default)]
+pub struct Feedback {
+    pub last: Option<Value>,
+}
+
+impl Nat for Feedback {
+    fn receive(&mut self, packet: Packet) -> ControlFlow<Value, Vec<Packet>> {
+        self.last = packet.payload.last().copied().or(self.last);
+
+        ControlFlow::Continue(vec![Packet {
+            address: 0,
+            ..packet
+        }])
+    }
+}
+
+/// Stops the network with the last value of the first packet sent outside it
+#[derive(Debug, Clone, Default)]
+pub struct FirstPacket;
+
+impl Nat for FirstPacket {
+    fn receive(&mut self, packet: Packet) -> ControlFlow<Value, Vec<Packet>> {
+        ControlFlow::Break(packet.payload.last().copied().unwrap_or_default())
+    }
+}
+
+/// Keeps the last packet sent outside the network, and sends it to machine 0 whenever the
+/// network goes idle. Stops with its last value once the same value is sent twice in a row
+#[derive(Debug, Clone, Default)]
+pub struct WakeOnIdle {
+    last: Option<Packet>,
+    last_sent: Option<Value>,
+}
+
+impl Nat for WakeOnIdle {
+    fn receive(&mut self, packet: Packet) -> ControlFlow<Value, Vec<Packet>> {
+        self.last = Some(packet);
+
+        ControlFlow::Continue(vec![])
+    }
+
+    fn idle(&mut self) -> ControlFlow<Value, Vec<Packet>> {
+        let Some(packet) = self.last.clone() else {
+            return ControlFlow::Continue(vec![]);
+        };
+
+        let value = packet.payload.last().copied();
+
+        if value.is_some() && value == self.last_sent {
+            return ControlFlow::Break(value.unwrap_or_default());
+        }
+
+        self.last_sent = value;
+
+        ControlFlow::Continue(vec![Packet {
+            address: 0,
+            ..packet
+        }])
+    }
+}
+
+/// Runs a program as a chain of amplifiers, one per phase setting, with the last
+/// amplifier's output fed back to the first. The first amplifier is sent a 0.
+///
+/// Returns the last signal the chain output, if any.
+pub fn feedback_loop(program: &[Value], phases: &[Value]) -> IntCodeResult<Option<Value>> {
+    let amplifiers = phases
+        .iter()
+        .map(|&phase| IntCodeExecutor::new(program).with_input([phase]));
+
+    let mut network = Network::new(amplifiers, Addressing::Chain);
+    let mut feedback = Feedback::default();
+
+    network.send(Packet {
+        address: 0,
+        payload: vec![0],
+    })?;
+    network.run(&mut feedback)?;
+
+    Ok(feedback.last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads its address, sends `[255, address, 42]`, then reads input forever
+    const SEND_ONCE: [Value; 14] = [3, 100, 104, 255, 4, 100, 104, 42, 3, 101, 1105, 1, 8, 99];
+
+    fn computers(count: usize) -> Network {
+        let machines = (0..count).map(|_| IntCodeExecutor::new(&SEND_ONCE));
+
+        Network::new(machines, Addressing::Packets { payload: 2 })
+            .with_addresses()
+            .with_empty_input(-1)
+    }
+
+    #[test]
+    fn amplifier_chain_examples() {
+        let ops = [
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ];
+
+        assert_eq!(feedback_loop(&ops, &[4, 3, 2, 1, 0]).unwrap(), Some(43210));
+    }
+
+    #[test]
+    fn feedback_loop_examples() {
+        let ops = [
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+
+        assert_eq!(
+            feedback_loop(&ops, &[9, 8, 7, 6, 5]).unwrap(),
+            Some(139629729)
+        );
+
+        let ops = [
+            3, 52, 1001, 52, -5, 52, 3, 53, 1, 52, 56, 54, 1007, 54, 5, 55, 1005, 55, 26, 1001, 54,
+            -5, 54, 1105, 1, 12, 1, 53, 54, 53, 1008, 54, 0, 55, 1001, 55, 1, 55, 2, 53, 55, 53, 4,
+            53, 1001, 56, -1, 56, 1005, 56, 6, 99, 0, 0, 0, 0, 10,
+        ];
+
+        assert_eq!(feedback_loop(&ops, &[9, 7, 8, 5, 6]).unwrap(), Some(18216));
+    }
+
+    #[test]
+    fn stops_on_the_first_packet_to_the_nat() {
+        assert_eq!(computers(3).run(&mut FirstPacket).unwrap(), Some(42));
+    }
+
+    #[test]
+    fn wakes_the_network_when_idle() {
+        let mut network = computers(3);
+        let mut nat = WakeOnIdle::default();
+
+        assert_eq!(
+            network.round(&mut nat).unwrap(),
+            ControlFlow::Continue(false)
+        );
+        assert_eq!(
+            network.round(&mut nat).unwrap(),
+            ControlFlow::Continue(true)
+        );
+        assert_eq!(
+            nat.last.as_ref().map(|packet| packet.payload.clone()),
+            Some(vec![2, 42])
+        );
+
+        assert_eq!(network.run(&mut nat).unwrap(), Some(42));
+    }
+
+    #[test]
+    fn is_deterministic() {
+        let mut network = computers(2);
+        let mut nat = WakeOnIdle::default();
+
+        assert_eq!(
+            network.round(&mut nat).unwrap(),
+            ControlFlow::Continue(false)
+        );
+
+        let mut copy = network.clone();
+        let mut copy_nat = nat.clone();
+
+        assert_eq!(
+            network.run(&mut nat).unwrap(),
+            copy.run(&mut copy_nat).unwrap()
+        );
+        assert_eq!(
+            network.machine(1).unwrap().memory(),
+            copy.machine(1).unwrap().memory()
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_addresses() {
+        assert!(matches!(
+            computers(1).send(Packet {
+                address: 7,
+                payload: vec![1]
+            }),
+            Err(Error::UnknownAddress(7))
+        ));
+    }
+}
@@ -0,0 +1,319 @@
+//! Watch what an executor does as it runs
+//!
+//! An [`Observer`] is passed to the `_with` variants of the executor's run methods,
+//! such as [`run_with`](crate::intcode::IntCodeExecutor::run_with), and is told about
+//! every instruction executed and every memory access, input and output it makes.
+//! Observers can be combined by passing a tuple of them.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::error::{Error, IntCodeResult};
+use crate::intcode::{Cursor, Instruction, Value};
+
+/// Hooks called as an executor runs, all of which do nothing by default
+pub trait Observer {
+    /// An instruction is about to execute. Returning an error stops the executor
+    /// with it, before the instruction runs
+    fn instruction(&mut self, _address: Cursor, _instruction: &Instruction) -> IntCodeResult<()> {
+        Ok(())
+    }
+
+    /// An instruction read a value from memory
+    fn read(&mut self, _address: Cursor, _value: Value) {}
+
+    /// An instruction wrote a value to memory
+    fn write(&mut self, _address: Cursor, _value: Value) {}
+
+    /// An input instruction took a value from the input queue
+    fn input(&mut self, _value: Value) {}
+
+    /// An output instruction output a value
+    fn output(&mut self, _value: Value) {}
+}
+
+/// Observes nothing, used by the executor's methods without an observer
+impl Observer for () {}
+
+impl<O: Observer + ?Sized> Observer for &mut O {
+    fn instruction(&mut self, address: Cursor, instruction: &Instruction) -> IntCodeResult<()> {
+        (**self).instruction(address, instruction)
+    }
+
+    fn read(&mut self, address: Cursor, value: Value) {
+        (**self).read(address, value);
+    }
+
+    fn write(&mut self, address: Cursor, value: Value) {
+        (**self).write(address, value);
+    }
+
+    fn input(&mut self, value: Value) {
+        (**self).input(value);
+    }
+
+    fn output(&mut self, value: Value) {
+        (**self).output(value);
+    }
+}
+
+/// Tells both observers, in order
+impl<A: Observer, B: Observer> Observer for (A, B) {
+    fn instruction(&mut self, address: Cursor, instruction: &Instruction) -> IntCodeResult<()> {
+        self.0.instruction(address, instruction)?;
+        self.1.instruction(address, instruction)
+    }
+
+    fn read(&mut self, address: Cursor, value: Value) {
+        self.0.read(address, value);
+        self.1.read(address, value);
+    }
+
+    fn write(&mut self, address: Cursor, value: Value) {
+        self.0.write(address, value);
+        self.1.write(address, value);
+    }
+
+    fn input(&mut self, value: Value) {
+        self.0.input(value);
+        self.1.input(value);
+    }
+
+    fn output(&mut self, value: Value) {
+        self.0.output(value);
+        self.1.output(value);
+    }
+}
+
+/// Counts the instructions executed, by address and by mnemonic
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    steps: usize,
+    by_address: BTreeMap<Cursor, usize>,
+    by_mnemonic: BTreeMap<&'static str, usize>,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Profile::default()
+    }
+
+    /// Number of instructions executed
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Number of instructions executed at each address
+    pub fn by_address(&self) -> &BTreeMap<Cursor, usize> {
+        &self.by_address
+    }
+
+    /// Number of times each kind of instruction was executed
+    pub fn by_mnemonic(&self) -> &BTreeMap<&'static str, usize> {
+        &self.by_mnemonic
+    }
+
+    /// The addresses executed most often, most first
+    pub fn hottest(&self, count: usize) -> Vec<(Cursor, usize)> {
+        let mut addresses: Vec<(Cursor, usize)> = self
+            .by_address
+            .iter()
+            .map(|(&address, &count)| (address, count))
+            .collect();
+
+        addresses.sort_by_key(|&(address, count)| (std::cmp::Reverse(count), address));
+        addresses.truncate(count);
+
+        addresses
+    }
+}
+
+impl Observer for Profile {
+    fn instruction(&mut self, address: Cursor, instruction: &Instruction) -> IntCodeResult<()> {
+        self.steps += 1;
+        *self.by_address.entry(address).or_default() += 1;
+        *self.by_mnemonic.entry(instruction.mnemonic()).or_default() += 1;
+
+        Ok(())
+    }
+}
+
+/// Stops the executor with [`Error::StepLimitExceeded`] when it tries to execute more
+/// than a number of instructions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepLimit {
+    limit: usize,
+    steps: usize,
+}
+
+impl StepLimit {
+    pub fn new(limit: usize) -> Self {
+        StepLimit { limit, steps: 0 }
+    }
+
+    /// Number of instructions executed
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+impl Observer for StepLimit {
+    fn instruction(&mut self, _address: Cursor, _instruction: &Instruction) -> IntCodeResult<()> {
+        if self.steps == self.limit {
+            return Err(Error::StepLimitExceeded(self.limit));
+        }
+
+        self.steps += 1;
+
+        Ok(())
+    }
+}
+
+/// Writes a line for every instruction and everything it does.
+///
+/// Writing stops at the first error, which [`finish`](Self::finish) returns.
+#[derive(Debug)]
+pub struct TraceLog<W: Write> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> TraceLog<W> {
+    pub fn new(writer: W) -> Self {
+        TraceLog {
+            writer,
+            error: None,
+        }
+    }
+
+    /// The writer, or the first error writing to it
+    pub fn finish(mut self) -> io::Result<W> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => self.writer.flush().map(|_| self.writer),
+        }
+    }
+
+    fn log(&mut self, line: std::fmt::Arguments) {
+        if self.error.is_none() {
+            self.error = writeln!(self.writer, "{line}").err();
+        }
+    }
+}
+
+impl<W: Write> Observer for TraceLog<W> {
+    fn instruction(&mut self, address: Cursor, instruction: &Instruction) -> IntCodeResult<()> {
+        self.log(format_args!("{address:04}  {instruction}"));
+
+        Ok(())
+    }
+
+    fn read(&mut self, address: Cursor, value: Value) {
+        self.log(format_args!("      read {address} = {value}"));
+    }
+
+    fn write(&mut self, address: Cursor, value: Value) {
+        self.log(format_args!("      write {address} = {value}"));
+    }
+
+    fn input(&mut self, value: Value) {
+        self.log(format_args!("      input {value}"));
+    }
+
+    fn output(&mut self, value: Value) {
+        self.log(format_args!("      output {value}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+    use crate::intcode::{IntCodeExecutor, State};
+
+    /// Counts down from its input, outputting each number
+    fn countdown() -> Vec<Value> {
+        assemble(
+            "
+                    in n
+            loop:   out n
+                    add n, #-1, n
+                    jt n, #loop
+                    hlt
+            n:      data 0
+            ",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn profiles_instructions() {
+        let mut profile = Profile::new();
+        let mut executor = IntCodeExecutor::new(&countdown()).with_input([3]);
+
+        assert_eq!(executor.run_with(&mut profile).unwrap(), State::Halted);
+        assert_eq!(executor.output(), [3, 2, 1]);
+
+        assert_eq!(profile.steps(), 11);
+        assert_eq!(profile.by_mnemonic()["out"], 3);
+        assert_eq!(profile.by_mnemonic()["hlt"], 1);
+        assert_eq!(profile.by_address()[&0], 1);
+        assert_eq!(profile.hottest(2), [(2, 3), (4, 3)]);
+    }
+
+    #[test]
+    fn waiting_on_input_is_not_a_step() {
+        let mut profile = Profile::new();
+        let mut executor = IntCodeExecutor::new(&countdown());
+
+        assert_eq!(executor.run_with(&mut profile).unwrap(), State::NeedsInput);
+        assert_eq!(executor.run_with(&mut profile).unwrap(), State::NeedsInput);
+        assert_eq!(profile.steps(), 0);
+    }
+
+    #[test]
+    fn stops_programs_that_never_halt() {
+        let forever = assemble("loop: jt #1, #loop").unwrap();
+        let mut limit = StepLimit::new(1000);
+
+        assert!(matches!(
+            IntCodeExecutor::new(&forever).execute_with(&mut limit),
+            Err(Error::StepLimitExceeded(1000))
+        ));
+        assert_eq!(limit.steps(), 1000);
+
+        let mut limit = StepLimit::new(11);
+        let executor = IntCodeExecutor::new(&countdown()).with_input([3]);
+
+        assert!(executor.execute_with(&mut limit).is_ok());
+    }
+
+    #[test]
+    fn logs_everything() {
+        let mut log = TraceLog::new(vec![]);
+        let mut executor = IntCodeExecutor::new(&countdown()).with_input([1]);
+        executor.run_with(&mut log).unwrap();
+
+        let log = String::from_utf8(log.finish().unwrap()).unwrap();
+
+        assert_eq!(
+            log,
+            "0000  in 12\n      input 1\n      write 12 = 1\n\
+             0002  out 12\n      read 12 = 1\n      output 1\n\
+             0004  add 12, #-1, 12\n      read 12 = 1\n      write 12 = 0\n\
+             0008  jt 12, #2\n      read 12 = 0\n\
+             0011  hlt\n"
+        );
+    }
+
+    #[test]
+    fn combines_observers() {
+        let mut observers = (StepLimit::new(5), Profile::new());
+        let result = IntCodeExecutor::new(&countdown())
+            .with_input([3])
+            .execute_with(&mut observers);
+
+        assert!(matches!(result, Err(Error::StepLimitExceeded(5))));
+        assert_eq!(observers.1.steps(), 5);
+    }
+}
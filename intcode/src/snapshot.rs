@@ -0,0 +1,225 @@
+//! Save an executor's state to bytes and restore it later
+//!
+//! A snapshot holds everything needed to carry on running: memory, the cursor, the
+//! relative base, the memory limit and any input or output still queued. Values are
+//! stored as variable length integers, so the zeros and small numbers most of memory
+//! holds take a byte each.
+
+use crate::error::{Error, IntCodeResult};
+use crate::intcode::{IntCodeExecutor, MemoryLimit, Value};
+
+/// Start of every snapshot, the last byte being the format's version
+const MAGIC: &[u8; 5] = b"INTC\x02";
+
+fn write_unsigned(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+
+    bytes.push(value as u8);
+}
+
+fn write_signed(bytes: &mut Vec<u8>, value: Value) {
+    let value = value as i64;
+    write_unsigned(bytes, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+fn write_values(bytes: &mut Vec<u8>, values: impl ExactSizeIterator<Item = Value>) {
+    write_unsigned(bytes, values.len() as u64);
+
+    for value in values {
+        write_signed(bytes, value);
+    }
+}
+
+/// Reads a snapshot's fields in the order they were written
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn unsigned(&mut self) -> IntCodeResult<u64> {
+        let mut value = 0;
+
+        for shift in (0..64).step_by(7) {
+            let [byte, rest @ ..] = self.bytes else {
+                return Err(Error::InvalidSnapshot("ends part way through"));
+            };
+
+            self.bytes = rest;
+            value |= u64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(Error::InvalidSnapshot("number too large"))
+    }
+
+    fn usize(&mut self) -> IntCodeResult<usize> {
+        usize::try_from(self.unsigned()?).map_err(|_| Error::InvalidSnapshot("number too large"))
+    }
+
+    fn signed(&mut self) -> IntCodeResult<Value> {
+        let value = self.unsigned()?;
+        let value = (value >> 1) as i64 ^ -((value & 1) as i64);
+
+        Value::try_from(value).map_err(|_| Error::InvalidSnapshot("number too large"))
+    }
+
+    fn values(&mut self) -> IntCodeResult<Vec<Value>> {
+        let count = self.usize()?;
+
+        // every value takes at least a byte, so don't trust a count larger than that
+        if count > self.bytes.len() {
+            return Err(Error::InvalidSnapshot("ends part way through"));
+        }
+
+        (0..count).map(|_| self.signed()).collect()
+    }
+}
+
+impl IntCodeExecutor {
+    /// Saves the executor's state, to be restored with [`restore`](Self::restore)
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+
+        match self.memory_limit {
+            MemoryLimit::Unbounded => bytes.push(0),
+            MemoryLimit::Capped(size) => {
+                bytes.push(1);
+                write_unsigned(&mut bytes, size as u64);
+            }
+        }
+
+        write_unsigned(&mut bytes, self.cursor as u64);
+        write_signed(&mut bytes, self.relative_base);
+        write_values(&mut bytes, self.memory.iter().copied());
+        write_values(&mut bytes, self.input.iter().copied());
+        write_values(&mut bytes, self.output.iter().copied());
+
+        bytes
+    }
+
+    /// Executor in the state a [`snapshot`](Self::snapshot) saved
+    pub fn restore(snapshot: &[u8]) -> IntCodeResult<Self> {
+        let bytes = snapshot
+            .strip_prefix(MAGIC)
+            .ok_or(Error::InvalidSnapshot("not an intcode snapshot"))?;
+        let mut reader = Reader { bytes };
+
+        let memory_limit = match reader.unsigned()? {
+            0 => MemoryLimit::Unbounded,
+            1 => MemoryLimit::Capped(reader.usize()?),
+            _ => return Err(Error::InvalidSnapshot("unknown memory limit")),
+        };
+
+        let executor = IntCodeExecutor {
+            memory_limit,
+            cursor: reader.usize()?,
+            relative_base: reader.signed()?,
+            memory: reader.values()?,
+            input: reader.values()?.into(),
+            output: reader.values()?,
+        };
+
+        if !reader.bytes.is_empty() {
+            return Err(Error::InvalidSnapshot("unexpected bytes at the end"));
+        }
+
+        Ok(executor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intcode::State;
+
+    /// Outputs each input plus 1000, keeping the last in address 20
+    fn adder() -> IntCodeExecutor {
+        IntCodeExecutor::new(&[3, 20, 1001, 20, 1000, 20, 4, 20, 1105, 1, 0])
+            .with_memory_limit(MemoryLimit::Capped(64))
+    }
+
+    #[test]
+    fn restores_where_it_left_off() {
+        let mut executor = adder().with_input([1, -5]);
+        assert_eq!(executor.run_until().unwrap(), State::Output(1001));
+
+        let snapshot = executor.snapshot();
+        let mut restored = IntCodeExecutor::restore(&snapshot).unwrap();
+
+        assert_eq!(restored.snapshot(), snapshot);
+        assert_eq!(restored.run().unwrap(), State::NeedsInput);
+        assert_eq!(restored.output(), [995]);
+        assert!(matches!(
+            restored.modify_with_address(64, 1),
+            Err(Error::OutOfBoundsWrite(64))
+        ));
+    }
+
+    #[test]
+    fn keeps_any_memory_limit() {
+        for limit in [
+            MemoryLimit::Unbounded,
+            MemoryLimit::Capped(0),
+            MemoryLimit::Capped(usize::MAX),
+        ] {
+            let executor = adder().with_memory_limit(limit);
+            let restored = IntCodeExecutor::restore(&executor.snapshot()).unwrap();
+
+            assert_eq!(restored.memory_limit, limit);
+        }
+    }
+
+    #[test]
+    fn forks_machines() {
+        let mut executor = adder();
+        executor.run().unwrap();
+
+        let branches: Vec<Value> = (0..3)
+            .map(|input| {
+                let mut branch = executor.clone();
+                branch.push_input(input);
+                branch.run().unwrap();
+
+                branch.output()[0]
+            })
+            .collect();
+
+        assert_eq!(branches, [1000, 1001, 1002]);
+        assert!(executor.output().is_empty());
+    }
+
+    #[test]
+    fn snapshots_are_compact() {
+        let mut executor = IntCodeExecutor::new(&[1101, 1, 1, 5000, 99]);
+        executor.run().unwrap();
+
+        // the zeros written past the end of the program take a byte each
+        assert_eq!(executor.memory().len(), 5001);
+        assert!(executor.snapshot().len() < 5001 + 20);
+    }
+
+    #[test]
+    fn rejects_invalid_snapshots() {
+        let snapshot = adder().with_input([1]).snapshot();
+
+        for invalid in [
+            &b"not a snapshot"[..],
+            &snapshot[..snapshot.len() - 1],
+            &[snapshot.as_slice(), &[0]].concat(),
+            &[MAGIC.as_slice(), &[0, 0, 0, 0xff]].concat(),
+            &[MAGIC.as_slice(), &[2, 0, 0, 0, 0, 0]].concat(),
+            &b"INTC\x01\0\0\0\0\0\0"[..],
+        ] {
+            assert!(matches!(
+                IntCodeExecutor::restore(invalid),
+                Err(Error::InvalidSnapshot(_))
+            ));
+        }
+    }
+}